@@ -0,0 +1,487 @@
+// ===============================
+//       ARRAY JOSIEVEC
+// -------------------------------
+// Fixed capacity vector that lives entirely inside a [MaybeUninit<T>; CAP] and never allocates.
+// Bulk writes go through the same kind of raii guard josievec_extend uses, so a panicking iterator
+// or clone leaves every element written so far counted in the length
+// -------------------------------
+
+use std::{
+    fmt,
+    iter::FusedIterator,
+    mem::{ManuallyDrop, MaybeUninit},
+    ops::{Deref, DerefMut, RangeBounds},
+    ptr::{copy, drop_in_place, read, slice_from_raw_parts_mut},
+    slice::{from_raw_parts, from_raw_parts_mut},
+};
+
+use crate::{
+    josie_slice_iter::{SliceCursor, SliceDrain, SliceDrainSource},
+    josie_vec::range_from_bounds,
+};
+
+///Vector with a fixed capacity of CAP elements stored inline. Never allocates
+pub struct ArrayJosieVec<T, const CAP: usize> {
+    buf: [MaybeUninit<T>; CAP],
+    len: usize,
+}
+
+//raii drop guard for array josievec, on drop adds every element written so far to the length
+struct ArrayJosieVecGuard<'a, T, const CAP: usize> {
+    array: &'a mut ArrayJosieVec<T, CAP>,
+    //number of elements written past the length since the guard was armed
+    written: usize,
+}
+
+impl<'a, T, const CAP: usize> ArrayJosieVecGuard<'a, T, CAP> {
+    //arms the guard at the current end of the array josievec
+    fn arm(array: &'a mut ArrayJosieVec<T, CAP>) -> Self {
+        Self { array, written: 0 }
+    }
+
+    //writes the element into the next free slot, panicking if there isnt one
+    #[inline(always)]
+    fn write(&mut self, element: T) {
+        let idx = self.array.len + self.written;
+        if idx == CAP {
+            panic!("Tried to extend ArrayJosieVec past its capacity of {CAP}")
+        }
+        self.array.buf[idx].write(element);
+        self.written += 1;
+    }
+}
+
+impl<T, const CAP: usize> Drop for ArrayJosieVecGuard<'_, T, CAP> {
+    fn drop(&mut self) {
+        //runs both on success and on panic, either way the written elements are initialized and owned by the array
+        self.array.len += self.written;
+    }
+}
+
+impl<T, const CAP: usize> ArrayJosieVec<T, CAP> {
+    ///Creates an empty ArrayJosieVec
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            buf: [const { MaybeUninit::uninit() }; CAP],
+            len: 0,
+        }
+    }
+
+    ///Number of elements in the ArrayJosieVec
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    ///Returns true if there are no elements
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    ///Returns true if every slot is in use
+    #[inline(always)]
+    pub const fn is_full(&self) -> bool {
+        self.len == CAP
+    }
+
+    ///The fixed capacity, always CAP
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        CAP
+    }
+
+    ///Number of elements that can still be pushed
+    #[inline(always)]
+    pub const fn remaining_capacity(&self) -> usize {
+        CAP - self.len
+    }
+
+    ///Returns a pointer to the first element
+    #[inline(always)]
+    pub const fn as_ptr(&self) -> *const T {
+        self.buf.as_ptr().cast()
+    }
+
+    ///Returns a mutable pointer to the first element
+    #[inline(always)]
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        self.buf.as_mut_ptr().cast()
+    }
+
+    ///Returns slice of the current contents
+    #[inline(always)]
+    pub const fn as_slice(&self) -> &[T] {
+        unsafe { from_raw_parts(self.as_ptr(), self.len) }
+    }
+
+    ///Returns mutable slice of the current contents
+    #[inline(always)]
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { from_raw_parts_mut(self.as_mut_ptr(), self.len) }
+    }
+
+    ///Sets the length without touching the elements
+    ///
+    /// # Safety
+    ///len must be less than or equal to CAP and every element up to len must be initialized.
+    #[inline(always)]
+    pub unsafe fn set_len(&mut self, len: usize) {
+        self.len = len;
+    }
+
+    ///Pushes an element onto the end. Panics if the ArrayJosieVec is full
+    #[inline]
+    pub fn push(&mut self, element: T) {
+        if self.try_push(element).is_err() {
+            panic!("Tried to push to a full ArrayJosieVec of capacity {CAP}")
+        }
+    }
+
+    ///Pushes an element onto the end, handing it back instead if the ArrayJosieVec is full
+    #[inline]
+    pub fn try_push(&mut self, element: T) -> Result<(), T> {
+        if self.len == CAP {
+            return Err(element);
+        }
+        self.buf[self.len].write(element);
+        self.len += 1;
+        Ok(())
+    }
+
+    ///Pops the last element. Returns None if there are no elements left
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(unsafe { self.buf[self.len].assume_init_read() })
+    }
+
+    ///Inserts element at index, shifting every element after it one to the right. Panics if index is
+    ///greater than the length or the ArrayJosieVec is full
+    pub fn insert(&mut self, index: usize, element: T) {
+        if self.try_insert(index, element).is_err() {
+            panic!("Tried to insert into a full ArrayJosieVec of capacity {CAP}")
+        }
+    }
+
+    ///Inserts element at index, handing it back instead if the ArrayJosieVec is full. Panics if index is
+    ///greater than the length
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), T> {
+        if index > self.len {
+            panic!("Tried to insert out of bounds element")
+        }
+        if self.len == CAP {
+            return Err(element);
+        }
+        unsafe {
+            let ptr = self.as_mut_ptr().add(index);
+            copy(ptr, ptr.add(1), self.len - index);
+            ptr.write(element);
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    ///Removes and returns the element at index, shifting every element after it one to the left
+    pub fn remove(&mut self, index: usize) -> T {
+        if index >= self.len {
+            panic!("Tried to remove out of bounds element")
+        }
+        self.len -= 1;
+        unsafe {
+            let ptr = self.as_mut_ptr().add(index);
+            let out = ptr.read();
+            copy(ptr.add(1), ptr, self.len - index);
+            out
+        }
+    }
+
+    ///Removes and returns the element at index, filling the gap with the last element
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        if index >= self.len {
+            panic!("Tried to swap remove out of bounds element")
+        }
+        self.len -= 1;
+        unsafe {
+            let ptr = self.as_mut_ptr();
+            let out = ptr.add(index).read();
+            copy(ptr.add(self.len), ptr.add(index), 1);
+            out
+        }
+    }
+
+    ///Drops every element past len
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            let old_len = self.len;
+            //sets the length first so a panicking drop cant drop anything twice
+            self.len = len;
+            unsafe {
+                drop_in_place(slice_from_raw_parts_mut(
+                    self.as_mut_ptr().add(len),
+                    old_len - len,
+                ));
+            }
+        }
+    }
+
+    ///Drops every element
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    ///Clones every element of slice onto the end. Panics if they dont all fit, keeping the ones that did
+    pub fn extend_from_slice(&mut self, slice: &[T])
+    where
+        T: Clone,
+    {
+        if slice.len() > self.remaining_capacity() {
+            panic!("Tried to extend ArrayJosieVec past its capacity of {CAP}")
+        }
+        self.extend(slice.iter().cloned());
+    }
+
+    ///Removes the elements in range and returns them as an iterator. Whatever the iterator doesnt yield is
+    ///dropped when it is, and the elements after the range are moved back to close the gap.
+    pub fn drain<R>(&mut self, range: R) -> ArrayJosieVecDrain<'_, T, CAP>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = range_from_bounds(range, self.len);
+        ArrayJosieVecDrain {
+            drain: SliceDrain::new(self, range),
+        }
+    }
+
+    ///Returns the elements as an array if every slot is in use, otherwise hands the ArrayJosieVec back
+    pub fn into_inner(self) -> Result<[T; CAP], Self> {
+        if self.len != CAP {
+            return Err(self);
+        }
+        let this = ManuallyDrop::new(self);
+        //every slot is initialized so the buffer is a valid array of T
+        Ok(unsafe { this.as_ptr().cast::<[T; CAP]>().read() })
+    }
+}
+
+impl<T, const CAP: usize> Drop for ArrayJosieVec<T, CAP> {
+    fn drop(&mut self) {
+        unsafe { drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+impl<T, const CAP: usize> Deref for ArrayJosieVec<T, CAP> {
+    type Target = [T];
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, const CAP: usize> DerefMut for ArrayJosieVec<T, CAP> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const CAP: usize> Default for ArrayJosieVec<T, CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const CAP: usize> Clone for ArrayJosieVec<T, CAP> {
+    fn clone(&self) -> Self {
+        let mut temp = Self::new();
+        temp.extend(self.iter().cloned());
+        temp
+    }
+}
+
+impl<T: fmt::Debug, const CAP: usize> fmt::Debug for ArrayJosieVec<T, CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+//array josievecs are equal if their elements are, no matter the capacity
+impl<T: PartialEq<U>, U, const CAP: usize, const M: usize> PartialEq<ArrayJosieVec<U, M>>
+    for ArrayJosieVec<T, CAP>
+{
+    fn eq(&self, other: &ArrayJosieVec<U, M>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U, const CAP: usize, const M: usize> PartialEq<[U; M]>
+    for ArrayJosieVec<T, CAP>
+{
+    fn eq(&self, other: &[U; M]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq<U>, U, const CAP: usize> PartialEq<[U]> for ArrayJosieVec<T, CAP> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: Eq, const CAP: usize> Eq for ArrayJosieVec<T, CAP> {}
+
+//writes through the raii guard so a panicking iterator leaves every element written so far in the array josievec.
+//panics if the iterator yields more elements than there is room for
+impl<T, const CAP: usize> Extend<T> for ArrayJosieVec<T, CAP> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut guard = ArrayJosieVecGuard::arm(self);
+        for element in iter {
+            guard.write(element);
+        }
+    }
+}
+
+impl<T, const CAP: usize> FromIterator<T> for ArrayJosieVec<T, CAP> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut temp = Self::new();
+        temp.extend(iter);
+        temp
+    }
+}
+
+impl<'a, T, const CAP: usize> IntoIterator for &'a ArrayJosieVec<T, CAP> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const CAP: usize> IntoIterator for &'a mut ArrayJosieVec<T, CAP> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, const CAP: usize> IntoIterator for ArrayJosieVec<T, CAP> {
+    type Item = T;
+    type IntoIter = ArrayJosieVecIter<T, CAP>;
+    fn into_iter(self) -> Self::IntoIter {
+        //moves the buffer out, the array josievec itself is never dropped so the elements are only owned once
+        let this = ManuallyDrop::new(self);
+        ArrayJosieVecIter {
+            buf: unsafe { read(&this.buf) },
+            cursor: SliceCursor::new(0..this.len),
+        }
+    }
+}
+
+// ===============================
+// ARRAY JOSIEVEC ITERATORS
+// -------------------------------
+
+///Owning iterator over an ArrayJosieVec
+pub struct ArrayJosieVecIter<T, const CAP: usize> {
+    buf: [MaybeUninit<T>; CAP],
+    cursor: SliceCursor,
+}
+
+impl<T, const CAP: usize> ArrayJosieVecIter<T, CAP> {
+    ///Returns the elements that havent been yielded yet as a slice
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { self.cursor.as_slice(self.buf.as_ptr().cast()) }
+    }
+}
+
+impl<T, const CAP: usize> Iterator for ArrayJosieVecIter<T, CAP> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        unsafe { self.cursor.next(self.buf.as_ptr().cast()) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.cursor.len();
+        (size, Some(size))
+    }
+}
+
+impl<T, const CAP: usize> DoubleEndedIterator for ArrayJosieVecIter<T, CAP> {
+    fn next_back(&mut self) -> Option<T> {
+        unsafe { self.cursor.next_back(self.buf.as_ptr().cast()) }
+    }
+}
+
+impl<T, const CAP: usize> ExactSizeIterator for ArrayJosieVecIter<T, CAP> {}
+impl<T, const CAP: usize> FusedIterator for ArrayJosieVecIter<T, CAP> {}
+
+impl<T, const CAP: usize> Drop for ArrayJosieVecIter<T, CAP> {
+    fn drop(&mut self) {
+        unsafe {
+            self.cursor
+                .drop_remaining(self.buf.as_mut_ptr().cast::<T>())
+        }
+    }
+}
+
+///Draining iterator for ArrayJosieVec, returned by ArrayJosieVec::drain
+pub struct ArrayJosieVecDrain<'a, T, const CAP: usize> {
+    drain: SliceDrain<'a, T, ArrayJosieVec<T, CAP>>,
+}
+
+impl<T, const CAP: usize> ArrayJosieVecDrain<'_, T, CAP> {
+    ///Returns the elements that havent been yielded yet as a slice
+    pub fn as_slice(&self) -> &[T] {
+        self.drain.as_slice()
+    }
+}
+
+impl<T, const CAP: usize> Iterator for ArrayJosieVecDrain<'_, T, CAP> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.drain.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drain.size_hint()
+    }
+}
+
+impl<T, const CAP: usize> DoubleEndedIterator for ArrayJosieVecDrain<'_, T, CAP> {
+    fn next_back(&mut self) -> Option<T> {
+        self.drain.next_back()
+    }
+}
+
+impl<T, const CAP: usize> ExactSizeIterator for ArrayJosieVecDrain<'_, T, CAP> {}
+impl<T, const CAP: usize> FusedIterator for ArrayJosieVecDrain<'_, T, CAP> {}
+
+impl<T, const CAP: usize> SliceDrainSource<T> for ArrayJosieVec<T, CAP> {
+    fn drain_ptr(&self) -> *const T {
+        self.as_ptr()
+    }
+
+    fn drain_mut_ptr(&mut self) -> *mut T {
+        self.as_mut_ptr()
+    }
+
+    fn drain_len(&self) -> usize {
+        self.len
+    }
+
+    unsafe fn drain_set_len(&mut self, len: usize) {
+        self.len = len;
+    }
+}
@@ -0,0 +1,378 @@
+// ===============================
+//       JOSIE BINARY HEAP
+// -------------------------------
+// Max heap stored in a josievec. ARITY sets how many children each node has, 2 is a classic binary
+// heap and wider heaps trade more comparisons per level for fewer levels and better cache use.
+// Sifting is done with swaps so a panicking Ord leaves every element in the heap, just out of order
+// -------------------------------
+
+use std::{
+    fmt,
+    iter::FusedIterator,
+    mem::swap,
+    ops::{Deref, DerefMut},
+};
+
+use crate::josie_vec::{josievec_drain::JosieVecDrain, josievec_iter::JosieVecIter, JosieVec};
+
+///Priority queue returning the greatest element first. Each node has ARITY children
+pub struct JosieBinaryHeap<T: Ord, const ARITY: usize = 2> {
+    data: JosieVec<T>,
+}
+
+impl<T: Ord, const ARITY: usize> JosieBinaryHeap<T, ARITY> {
+    ///Creates an empty heap
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::from(JosieVec::new())
+    }
+
+    ///Creates an empty heap with room for cap elements
+    pub fn with_capacity(cap: usize) -> Self {
+        Self::from(JosieVec::with_capacity(cap))
+    }
+
+    ///Number of elements in the heap
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    ///Returns true if there are no elements
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    ///Number of elements that fit before the heap reallocates
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    ///Reserves room for at least additional more elements
+    #[inline(always)]
+    pub fn reserve(&mut self, additional: usize) {
+        self.data.reserve(additional)
+    }
+
+    ///Returns the elements in heap order, which is not sorted order
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_slice()
+    }
+
+    ///Returns an iterator over the elements in heap order
+    #[inline(always)]
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.as_slice().iter()
+    }
+
+    ///Returns the greatest element without removing it
+    #[inline(always)]
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    ///Returns a guard to the greatest element that can change it in place. If it was changed the heap is
+    ///fixed up when the guard drops
+    pub fn peek_mut(&mut self) -> Option<JosieBinaryHeapPeekMut<'_, T, ARITY>> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(JosieBinaryHeapPeekMut {
+            heap: self,
+            sift: false,
+        })
+    }
+
+    ///Pushes an element onto the heap. O(log n)
+    pub fn push(&mut self, element: T) {
+        self.data.push(element);
+        self.sift_up(self.len() - 1);
+    }
+
+    ///Removes and returns the greatest element. O(log n)
+    pub fn pop(&mut self) -> Option<T> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        //moves the last element to the root then lets it sink to where it belongs
+        self.data.swap(0, len - 1);
+        let out = self.data.pop();
+        self.sift_down_range(0, len - 1);
+        out
+    }
+
+    ///Moves every element of other into this heap, leaving other empty
+    pub fn append(&mut self, other: &mut Self) {
+        //always moves the smaller heap into the bigger one
+        if self.len() < other.len() {
+            swap(self, other);
+        }
+        let start = self.len();
+        self.data.append(&mut other.data);
+        //rebuilding is O(n) while pushing each is O(k log n), so it wins once other is a big part of the heap
+        if other_is_large(start, self.len() - start) {
+            self.rebuild();
+        } else {
+            for i in start..self.len() {
+                self.sift_up(i);
+            }
+        }
+    }
+
+    ///Keeps only the elements the predicate returns true for, then rebuilds the heap
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let len = self.len();
+        self.data.retain(f);
+        if self.len() != len {
+            self.rebuild();
+        }
+    }
+
+    ///Drops every element
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.data.clear()
+    }
+
+    ///Removes every element and returns them in heap order
+    pub fn drain(&mut self) -> JosieVecDrain<'_, T> {
+        self.data.drain(..)
+    }
+
+    ///Removes the elements greatest first. Whatever the iterator doesnt yield is dropped when it is
+    pub fn drain_sorted(&mut self) -> JosieBinaryHeapDrainSorted<'_, T, ARITY> {
+        JosieBinaryHeapDrainSorted { heap: self }
+    }
+
+    ///Returns the josievec the heap is stored in, in heap order
+    #[inline(always)]
+    pub fn into_josievec(self) -> JosieVec<T> {
+        self.data
+    }
+
+    ///Sorts the heap in place and returns it as a josievec in ascending order. O(n log n)
+    pub fn into_sorted_vec(mut self) -> JosieVec<T> {
+        let mut end = self.len();
+        while end > 1 {
+            end -= 1;
+            //the greatest remaining element goes to the end of the unsorted part
+            self.data.swap(0, end);
+            self.sift_down_range(0, end);
+        }
+        self.data
+    }
+}
+
+//Internal methods
+impl<T: Ord, const ARITY: usize> JosieBinaryHeap<T, ARITY> {
+    //moves the element at pos up until its parent is at least as great
+    fn sift_up(&mut self, mut pos: usize) {
+        while pos > 0 {
+            let parent = (pos - 1) / ARITY;
+            if self.data[pos] <= self.data[parent] {
+                break;
+            }
+            self.data.swap(pos, parent);
+            pos = parent;
+        }
+    }
+
+    //moves the element at pos down until every child below end is at most as great
+    fn sift_down_range(&mut self, mut pos: usize, end: usize) {
+        loop {
+            //first child of pos, stopping at the bottom of the heap
+            let first = match pos.checked_mul(ARITY).and_then(|child| child.checked_add(1)) {
+                Some(first) if first < end => first,
+                _ => break,
+            };
+            let last = first.saturating_add(ARITY).min(end);
+            //finds the greatest child
+            let mut greatest = first;
+            for child in first + 1..last {
+                if self.data[child] > self.data[greatest] {
+                    greatest = child;
+                }
+            }
+            if self.data[greatest] <= self.data[pos] {
+                break;
+            }
+            self.data.swap(pos, greatest);
+            pos = greatest;
+        }
+    }
+
+    //restores the heap property over every element. O(n)
+    fn rebuild(&mut self) {
+        let len = self.len();
+        if len < 2 {
+            return;
+        }
+        //every node past the last parent is a leaf and already a heap on its own
+        let mut pos = (len - 2) / ARITY + 1;
+        while pos > 0 {
+            pos -= 1;
+            self.sift_down_range(pos, len);
+        }
+    }
+}
+
+//rough cost comparison of rebuilding against sifting each appended element up
+fn other_is_large(len: usize, added: usize) -> bool {
+    let log = usize::BITS - len.leading_zeros();
+    2 * (len + added) < added * log as usize
+}
+
+///Guard returned by JosieBinaryHeap::peek_mut. Derefs to the greatest element and sifts it back into place on
+///drop if it was changed
+pub struct JosieBinaryHeapPeekMut<'a, T: Ord, const ARITY: usize = 2> {
+    heap: &'a mut JosieBinaryHeap<T, ARITY>,
+    //set once the element is handed out mutably
+    sift: bool,
+}
+
+impl<T: Ord, const ARITY: usize> JosieBinaryHeapPeekMut<'_, T, ARITY> {
+    ///Removes the peeked element from the heap and returns it
+    pub fn pop(mut this: Self) -> T {
+        //the pop below fixes the heap up itself
+        this.sift = false;
+        this.heap.pop().expect("JosieBinaryHeapPeekMut is only made for non empty heaps")
+    }
+}
+
+impl<T: Ord, const ARITY: usize> Deref for JosieBinaryHeapPeekMut<'_, T, ARITY> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.heap.data[0]
+    }
+}
+
+impl<T: Ord, const ARITY: usize> DerefMut for JosieBinaryHeapPeekMut<'_, T, ARITY> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.sift = true;
+        &mut self.heap.data[0]
+    }
+}
+
+impl<T: Ord, const ARITY: usize> Drop for JosieBinaryHeapPeekMut<'_, T, ARITY> {
+    fn drop(&mut self) {
+        if self.sift {
+            let len = self.heap.len();
+            self.heap.sift_down_range(0, len);
+        }
+    }
+}
+
+///Iterator returned by JosieBinaryHeap::drain_sorted, pops the greatest element each step
+pub struct JosieBinaryHeapDrainSorted<'a, T: Ord, const ARITY: usize = 2> {
+    heap: &'a mut JosieBinaryHeap<T, ARITY>,
+}
+
+impl<T: Ord, const ARITY: usize> Iterator for JosieBinaryHeapDrainSorted<'_, T, ARITY> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.heap.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.heap.len();
+        (size, Some(size))
+    }
+}
+
+impl<T: Ord, const ARITY: usize> ExactSizeIterator for JosieBinaryHeapDrainSorted<'_, T, ARITY> {}
+impl<T: Ord, const ARITY: usize> FusedIterator for JosieBinaryHeapDrainSorted<'_, T, ARITY> {}
+
+impl<T: Ord, const ARITY: usize> Drop for JosieBinaryHeapDrainSorted<'_, T, ARITY> {
+    fn drop(&mut self) {
+        //the rest are dropped in heap order, there is no need to pop them one by one
+        self.heap.clear();
+    }
+}
+
+//heapifies the josievec in place. O(n)
+impl<T: Ord, const ARITY: usize> From<JosieVec<T>> for JosieBinaryHeap<T, ARITY> {
+    fn from(josievec: JosieVec<T>) -> Self {
+        //checked at compile time, a node needs at least two children to form a heap
+        const { assert!(ARITY >= 2, "JosieBinaryHeap needs an arity of at least 2") };
+        let mut heap = Self { data: josievec };
+        heap.rebuild();
+        heap
+    }
+}
+
+impl<T: Ord, const ARITY: usize> From<JosieBinaryHeap<T, ARITY>> for JosieVec<T> {
+    fn from(heap: JosieBinaryHeap<T, ARITY>) -> Self {
+        heap.data
+    }
+}
+
+impl<T: Ord, const ARITY: usize> Default for JosieBinaryHeap<T, ARITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Clone, const ARITY: usize> Clone for JosieBinaryHeap<T, ARITY> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<T: Ord + fmt::Debug, const ARITY: usize> fmt::Debug for JosieBinaryHeap<T, ARITY> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<T: Ord, const ARITY: usize> Extend<T> for JosieBinaryHeap<T, ARITY> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let start = self.len();
+        self.data.extend(iter);
+        if other_is_large(start, self.len() - start) {
+            self.rebuild();
+        } else {
+            for i in start..self.len() {
+                self.sift_up(i);
+            }
+        }
+    }
+}
+
+impl<T: Ord, const ARITY: usize> FromIterator<T> for JosieBinaryHeap<T, ARITY> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::from(JosieVec::from_iter(iter))
+    }
+}
+
+//yields the elements in heap order
+impl<T: Ord, const ARITY: usize> IntoIterator for JosieBinaryHeap<T, ARITY> {
+    type Item = T;
+    type IntoIter = JosieVecIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T: Ord, const ARITY: usize> IntoIterator for &'a JosieBinaryHeap<T, ARITY> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
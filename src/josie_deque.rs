@@ -0,0 +1,655 @@
+// ===============================
+//       JOSIEDEQUE
+// -------------------------------
+// Growable ring buffer on top of the same RawJosieVec storage josievec uses. The elements start at
+// head and wrap around the end of the buffer, so pushing and popping at either end is O(1). When
+// the buffer grows the wrapped part is moved into the new space so the ring stays in order
+// -------------------------------
+
+use std::{
+    fmt,
+    iter::FusedIterator,
+    mem::{ManuallyDrop, MaybeUninit},
+    ops::{Index, IndexMut, RangeBounds},
+    ptr::{copy, copy_nonoverlapping, drop_in_place, read},
+    slice::{self, from_raw_parts, from_raw_parts_mut},
+};
+
+use crate::{
+    josie_slice_iter::{SliceDrain, SliceDrainSource},
+    josie_vec::{
+        handle_reserve_error, is_zst,
+        josievec_alloc::{Global, JosieAllocator},
+        josievec_error::JosieTryReserveError,
+        josievec_growth::{Doubling, GrowthPolicy},
+        range_from_bounds, JosieVec, RawJosieVec,
+    },
+};
+
+///Double ended queue stored as a growable ring buffer
+pub struct JosieDeque<T, A: JosieAllocator = Global> {
+    buf: RawJosieVec<T, A>,
+    //physical index of the first element
+    head: usize,
+    len: usize,
+}
+
+//Constructors using the global allocator
+impl<T> JosieDeque<T> {
+    ///Creates an empty JosieDeque without allocating
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self::new_in(Global)
+    }
+
+    ///Creates an empty JosieDeque with room for cap elements
+    pub fn with_capacity(cap: usize) -> Self {
+        Self::with_capacity_in(cap, Global)
+    }
+}
+
+impl<T, A: JosieAllocator> JosieDeque<T, A> {
+    ///Creates an empty JosieDeque that allocates from the allocator passed in
+    #[inline(always)]
+    pub const fn new_in(alloc: A) -> Self {
+        Self {
+            buf: RawJosieVec::new_in(alloc),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    ///Creates an empty JosieDeque with room for cap elements taken from the allocator passed in
+    pub fn with_capacity_in(cap: usize, alloc: A) -> Self {
+        let mut temp = Self::new_in(alloc);
+        temp.reserve_exact(cap);
+        temp
+    }
+
+    ///Returns a reference to the allocator backing this JosieDeque
+    #[inline(always)]
+    pub const fn allocator(&self) -> &A {
+        &self.buf.alloc
+    }
+
+    ///Number of elements in the JosieDeque
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    ///Returns true if there are no elements
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    ///Number of elements that fit before the buffer grows
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        self.buf.capacity()
+    }
+
+    ///Reserves room for at least additional more elements, doubling the capacity until they fit
+    pub fn reserve(&mut self, additional: usize) {
+        if let Err(err) = self.try_reserve(additional) {
+            handle_reserve_error(err)
+        }
+    }
+
+    ///Reserves room for exactly additional more elements
+    pub fn reserve_exact(&mut self, additional: usize) {
+        let result = self
+            .len
+            .checked_add(additional)
+            .ok_or(JosieTryReserveError::CapacityOverflow)
+            .and_then(|required| self.try_grow_to(required));
+        if let Err(err) = result {
+            handle_reserve_error(err)
+        }
+    }
+
+    ///Fallible version of reserve. Returns an error instead of panicking if the capacity overflows or the
+    ///allocator fails
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), JosieTryReserveError> {
+        let required = self
+            .len
+            .checked_add(additional)
+            .ok_or(JosieTryReserveError::CapacityOverflow)?;
+        if required <= self.capacity() {
+            return Ok(());
+        }
+        //always doubles on purpose, the deque has no growth policy parameter. a policy would also have to shrink,
+        //and a wrapped ring cant be shrunk in place the way a josievec can
+        let new_capacity = Doubling
+            .grow(self.capacity(), required, size_of::<T>())
+            .ok_or(JosieTryReserveError::CapacityOverflow)?;
+        self.try_grow_to(new_capacity)
+    }
+
+    ///Pushes an element onto the back
+    pub fn push_back(&mut self, element: T) {
+        if self.len == self.capacity() {
+            self.reserve(1);
+        }
+        unsafe { self.ptr_at(self.to_physical(self.len)).write(element) }
+        self.len += 1;
+    }
+
+    ///Pushes an element onto the front
+    pub fn push_front(&mut self, element: T) {
+        if self.len == self.capacity() {
+            self.reserve(1);
+        }
+        //moves head back one slot, wrapping to the end of the buffer
+        self.head = match self.head {
+            0 => self.capacity() - 1,
+            head => head - 1,
+        };
+        unsafe { self.ptr_at(self.head).write(element) }
+        self.len += 1;
+    }
+
+    ///Removes and returns the first element. Returns None if there are no elements
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let head = self.head;
+        self.head = self.to_physical(1);
+        self.len -= 1;
+        Some(unsafe { self.ptr_at(head).read() })
+    }
+
+    ///Removes and returns the last element. Returns None if there are no elements
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(unsafe { self.ptr_at(self.to_physical(self.len)).read() })
+    }
+
+    ///Returns a reference to the element at index, counted from the front
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        Some(unsafe { &*self.ptr_at(self.to_physical(index)) })
+    }
+
+    ///Returns a mutable reference to the element at index, counted from the front
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        Some(unsafe { &mut *self.ptr_at(self.to_physical(index)) })
+    }
+
+    ///Returns a reference to the first element
+    #[inline(always)]
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    ///Returns a reference to the last element
+    #[inline(always)]
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.wrapping_sub(1))
+    }
+
+    ///Returns a mutable reference to the first element
+    #[inline(always)]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    ///Returns a mutable reference to the last element
+    #[inline(always)]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len.wrapping_sub(1))
+    }
+
+    ///Returns the elements as two slices in order. The second slice is only non empty when the elements wrap
+    ///around the end of the buffer
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (first, second) = self.slice_ranges();
+        unsafe {
+            (
+                from_raw_parts(self.ptr_at(self.head), first),
+                from_raw_parts(self.ptr_at(0), second),
+            )
+        }
+    }
+
+    ///Mutable version of as_slices
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (first, second) = self.slice_ranges();
+        unsafe {
+            (
+                from_raw_parts_mut(self.ptr_at(self.head), first),
+                from_raw_parts_mut(self.ptr_at(0), second),
+            )
+        }
+    }
+
+    ///Moves the elements so they are stored in one piece starting at the front of the buffer and returns
+    ///them as a slice. Does nothing but return the slice if they already are
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if is_zst::<T>() {
+            //zero sized types all live at the same address so there is nothing to move
+            self.head = 0;
+        } else if self.head != 0 {
+            unsafe {
+                //rotating the whole buffer moves the head to index zero and keeps every element in order,
+                //the slots are MaybeUninit so rotating the unused ones is fine
+                let buffer = from_raw_parts_mut(
+                    self.buf.ptr.as_ptr().cast::<MaybeUninit<T>>(),
+                    self.capacity(),
+                );
+                buffer.rotate_left(self.head);
+            }
+            self.head = 0;
+        }
+        unsafe { from_raw_parts_mut(self.ptr_at(0), self.len) }
+    }
+
+    ///Drops every element past len
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        let old_len = self.len;
+        //sets the length first so a panicking drop cant drop anything twice
+        self.len = len;
+        for offset in len..old_len {
+            unsafe { drop_in_place(self.ptr_at(self.to_physical(offset))) }
+        }
+    }
+
+    ///Drops every element
+    pub fn clear(&mut self) {
+        self.truncate(0);
+        self.head = 0;
+    }
+
+    ///Removes the elements in range and returns them as an iterator. The deque is made contiguous first,
+    ///then whatever the iterator doesnt yield is dropped when it is and the elements after the range are
+    ///moved back to close the gap.
+    pub fn drain<R>(&mut self, range: R) -> JosieDequeDrain<'_, T, A>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = range_from_bounds(range, self.len);
+        self.make_contiguous();
+        JosieDequeDrain {
+            drain: SliceDrain::new(self, range),
+        }
+    }
+
+    ///Returns an iterator over references to the elements from front to back
+    pub fn iter(&self) -> JosieDequeIterRef<'_, T> {
+        let (first, second) = self.as_slices();
+        JosieDequeIterRef {
+            first: first.iter(),
+            second: second.iter(),
+        }
+    }
+
+    ///Returns an iterator over mutable references to the elements from front to back
+    pub fn iter_mut(&mut self) -> JosieDequeIterMut<'_, T> {
+        let (first, second) = self.as_mut_slices();
+        JosieDequeIterMut {
+            first: first.iter_mut(),
+            second: second.iter_mut(),
+        }
+    }
+}
+
+//Internal methods
+impl<T, A: JosieAllocator> JosieDeque<T, A> {
+    //turns an offset from the front into an index into the buffer. compares against the room left after head instead
+    //of adding first so zero sized types with a capacity of usize::MAX cant overflow
+    #[inline(always)]
+    fn to_physical(&self, offset: usize) -> usize {
+        let room = self.capacity() - self.head;
+        if offset >= room {
+            offset - room
+        } else {
+            self.head + offset
+        }
+    }
+
+    //pointer to the slot at a physical index
+    #[inline(always)]
+    fn ptr_at(&self, physical: usize) -> *mut T {
+        unsafe { self.buf.ptr.as_ptr().add(physical) }
+    }
+
+    //lengths of the part from head to the end of the buffer and the part wrapped round to the start
+    #[inline(always)]
+    fn slice_ranges(&self) -> (usize, usize) {
+        let room = self.capacity() - self.head;
+        if self.len > room {
+            (room, self.len - room)
+        } else {
+            (self.len, 0)
+        }
+    }
+
+    //reallocates to new_capacity and moves the wrapped part so the ring stays in order
+    fn try_grow_to(&mut self, new_capacity: usize) -> Result<(), JosieTryReserveError> {
+        let old_capacity = self.capacity();
+        if new_capacity <= old_capacity {
+            return Ok(());
+        }
+        unsafe { self.buf.try_realloc(new_capacity)? }
+        //zero sized types never reallocate so head can only wrap for real elements
+        let head_len = old_capacity - self.head;
+        if self.len > head_len {
+            let tail_len = self.len - head_len;
+            unsafe {
+                if tail_len <= new_capacity - old_capacity {
+                    //the wrapped part fits in the new space right after the old end
+                    copy_nonoverlapping(self.ptr_at(0), self.ptr_at(old_capacity), tail_len);
+                } else {
+                    //otherwise moves the head part to the end of the new buffer
+                    let new_head = new_capacity - head_len;
+                    copy(self.ptr_at(self.head), self.ptr_at(new_head), head_len);
+                    self.head = new_head;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T, A: JosieAllocator> Drop for JosieDeque<T, A> {
+    fn drop(&mut self) {
+        let (first, second) = self.as_mut_slices();
+        let (first, second) = (first as *mut [T], second as *mut [T]);
+        unsafe {
+            drop_in_place(first);
+            drop_in_place(second);
+        }
+        //the buffer itself is deallocated when buf drops
+    }
+}
+
+impl<T, A: JosieAllocator> Index<usize> for JosieDeque<T, A> {
+    type Output = T;
+    fn index(&self, index: usize) -> &T {
+        let len = self.len;
+        self.get(index).unwrap_or_else(|| {
+            panic!("Index {index} is out of range for JosieDeque of length {len}")
+        })
+    }
+}
+
+impl<T, A: JosieAllocator> IndexMut<usize> for JosieDeque<T, A> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len;
+        self.get_mut(index).unwrap_or_else(|| {
+            panic!("Index {index} is out of range for JosieDeque of length {len}")
+        })
+    }
+}
+
+impl<T, A: JosieAllocator + Default> Default for JosieDeque<T, A> {
+    fn default() -> Self {
+        Self::new_in(A::default())
+    }
+}
+
+impl<T: Clone, A: JosieAllocator + Clone> Clone for JosieDeque<T, A> {
+    fn clone(&self) -> Self {
+        let mut temp = Self::with_capacity_in(self.len, self.buf.alloc.clone());
+        temp.extend(self.iter().cloned());
+        temp
+    }
+}
+
+impl<T: fmt::Debug, A: JosieAllocator> fmt::Debug for JosieDeque<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+//deques are equal if their elements are in the same order, no matter where the ring wraps
+impl<T: PartialEq<U>, U, A1: JosieAllocator, A2: JosieAllocator> PartialEq<JosieDeque<U, A2>>
+    for JosieDeque<T, A1>
+{
+    fn eq(&self, other: &JosieDeque<U, A2>) -> bool {
+        self.len == other.len && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: PartialEq<U>, U, A: JosieAllocator, const N: usize> PartialEq<[U; N]> for JosieDeque<T, A> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.len == N && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: Eq, A: JosieAllocator> Eq for JosieDeque<T, A> {}
+
+impl<T, A: JosieAllocator> Extend<T> for JosieDeque<T, A> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iterator = iter.into_iter();
+        self.reserve(iterator.size_hint().0);
+        for element in iterator {
+            self.push_back(element);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for JosieDeque<T> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut temp = Self::new();
+        temp.extend(iter);
+        temp
+    }
+}
+
+//takes over the josievec buffer as is, the elements already start at the front. the growth policy is
+//dropped since the deque always doubles
+impl<T, A: JosieAllocator, G: GrowthPolicy> From<JosieVec<T, A, G>> for JosieDeque<T, A> {
+    fn from(josievec: JosieVec<T, A, G>) -> Self {
+        let josievec = ManuallyDrop::new(josievec);
+        unsafe {
+            //the josievec is never dropped so the buffer is only owned once
+            drop(read(&josievec.policy));
+            Self {
+                buf: read(&josievec.buf),
+                head: 0,
+                len: josievec.len,
+            }
+        }
+    }
+}
+
+//makes the deque contiguous at the front of the buffer then hands the buffer to a josievec, which doubles
+//like the deque did
+impl<T, A: JosieAllocator> From<JosieDeque<T, A>> for JosieVec<T, A> {
+    fn from(mut deque: JosieDeque<T, A>) -> Self {
+        deque.make_contiguous();
+        let deque = ManuallyDrop::new(deque);
+        JosieVec {
+            buf: unsafe { read(&deque.buf) },
+            len: deque.len,
+            policy: Doubling,
+        }
+    }
+}
+
+impl<'a, T, A: JosieAllocator> IntoIterator for &'a JosieDeque<T, A> {
+    type Item = &'a T;
+    type IntoIter = JosieDequeIterRef<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: JosieAllocator> IntoIterator for &'a mut JosieDeque<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = JosieDequeIterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, A: JosieAllocator> IntoIterator for JosieDeque<T, A> {
+    type Item = T;
+    type IntoIter = JosieDequeIter<T, A>;
+    fn into_iter(self) -> Self::IntoIter {
+        JosieDequeIter { deque: self }
+    }
+}
+
+// ===============================
+// JOSIEDEQUE ITERATORS
+// -------------------------------
+// The borrowing iterators walk the two slices from as_slices one after the other
+// -------------------------------
+
+macro_rules! two_slice_iterator {
+    ($name:ident, $item:ty) => {
+        impl<'a, T> Iterator for $name<'a, T> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                //moves on to the wrapped part once the first part runs out
+                self.first.next().or_else(|| self.second.next())
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let size = self.first.len() + self.second.len();
+                (size, Some(size))
+            }
+        }
+
+        impl<'a, T> DoubleEndedIterator for $name<'a, T> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.second.next_back().or_else(|| self.first.next_back())
+            }
+        }
+
+        impl<T> ExactSizeIterator for $name<'_, T> {}
+        impl<T> FusedIterator for $name<'_, T> {}
+    };
+}
+
+///Iterator over references to the elements of a JosieDeque
+pub struct JosieDequeIterRef<'a, T> {
+    first: slice::Iter<'a, T>,
+    second: slice::Iter<'a, T>,
+}
+
+impl<T> Clone for JosieDequeIterRef<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            first: self.first.clone(),
+            second: self.second.clone(),
+        }
+    }
+}
+
+two_slice_iterator!(JosieDequeIterRef, &'a T);
+
+///Iterator over mutable references to the elements of a JosieDeque
+pub struct JosieDequeIterMut<'a, T> {
+    first: slice::IterMut<'a, T>,
+    second: slice::IterMut<'a, T>,
+}
+
+two_slice_iterator!(JosieDequeIterMut, &'a mut T);
+
+///Owning iterator over a JosieDeque, pops from whichever end is asked for
+pub struct JosieDequeIter<T, A: JosieAllocator = Global> {
+    deque: JosieDeque<T, A>,
+}
+
+impl<T, A: JosieAllocator> JosieDequeIter<T, A> {
+    ///Returns the elements that havent been yielded yet as a deque
+    pub fn into_deque(self) -> JosieDeque<T, A> {
+        self.deque
+    }
+}
+
+impl<T, A: JosieAllocator> Iterator for JosieDequeIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.deque.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.deque.len, Some(self.deque.len))
+    }
+}
+
+impl<T, A: JosieAllocator> DoubleEndedIterator for JosieDequeIter<T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        self.deque.pop_back()
+    }
+}
+
+impl<T, A: JosieAllocator> ExactSizeIterator for JosieDequeIter<T, A> {}
+impl<T, A: JosieAllocator> FusedIterator for JosieDequeIter<T, A> {}
+
+///Draining iterator for JosieDeque, returned by JosieDeque::drain
+pub struct JosieDequeDrain<'a, T, A: JosieAllocator = Global> {
+    drain: SliceDrain<'a, T, JosieDeque<T, A>>,
+}
+
+impl<T, A: JosieAllocator> JosieDequeDrain<'_, T, A> {
+    ///Returns the elements that havent been yielded yet as a slice
+    pub fn as_slice(&self) -> &[T] {
+        self.drain.as_slice()
+    }
+}
+
+impl<T, A: JosieAllocator> Iterator for JosieDequeDrain<'_, T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.drain.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drain.size_hint()
+    }
+}
+
+impl<T, A: JosieAllocator> DoubleEndedIterator for JosieDequeDrain<'_, T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        self.drain.next_back()
+    }
+}
+
+impl<T, A: JosieAllocator> ExactSizeIterator for JosieDequeDrain<'_, T, A> {}
+impl<T, A: JosieAllocator> FusedIterator for JosieDequeDrain<'_, T, A> {}
+
+//drain makes the deque contiguous from index zero first, so the elements start at the front of the buffer
+impl<T, A: JosieAllocator> SliceDrainSource<T> for JosieDeque<T, A> {
+    fn drain_ptr(&self) -> *const T {
+        self.ptr_at(0)
+    }
+
+    fn drain_mut_ptr(&mut self) -> *mut T {
+        self.ptr_at(0)
+    }
+
+    fn drain_len(&self) -> usize {
+        self.len
+    }
+
+    unsafe fn drain_set_len(&mut self, len: usize) {
+        self.len = len;
+    }
+}
@@ -0,0 +1,1069 @@
+// ===============================
+//       JOSIE HASH MAP
+// -------------------------------
+// Open addressing hash table stored in two RawJosieVec buffers, one control byte per bucket and one
+// slot per bucket. A control byte is EMPTY, DELETED or the top 7 bits of the hash of the key in the
+// slot, so most probes that land on the wrong key are turned away without ever comparing keys. The
+// bucket count is always a power of two and at most 7/8 of the buckets are ever in use, so a linear
+// probe from the hash always runs into an EMPTY bucket and stops
+// -------------------------------
+
+use std::{
+    borrow::Borrow,
+    fmt,
+    hash::{BuildHasher, Hash, RandomState},
+    iter::FusedIterator,
+    marker::PhantomData,
+    mem::{self, needs_drop},
+    ops::Index,
+    ptr::{copy_nonoverlapping, drop_in_place},
+};
+
+use crate::josie_vec::{
+    handle_reserve_error, josievec_alloc::Global, josievec_error::JosieTryReserveError,
+    RawJosieVec,
+};
+
+//control byte of a bucket that has never held anything, every probe stops here
+const EMPTY: u8 = 0xFF;
+//control byte of a bucket whose element was removed, probes carry on past it
+const DELETED: u8 = 0x80;
+
+//full buckets store the top 7 bits of the hash, so their high bit is always clear
+#[inline(always)]
+fn is_full(ctrl: u8) -> bool {
+    ctrl & 0x80 == 0
+}
+
+//the part of the hash kept in the control byte
+#[inline(always)]
+fn h2(hash: u64) -> u8 {
+    (hash >> 57) as u8
+}
+
+#[inline(always)]
+pub(crate) fn make_hash<Q: Hash + ?Sized, S: BuildHasher>(hasher: &S, key: &Q) -> u64 {
+    hasher.hash_one(key)
+}
+
+//number of buckets needed to hold cap elements without going over the load factor
+fn capacity_to_buckets(cap: usize) -> Option<usize> {
+    if cap == 0 {
+        return Some(0);
+    }
+    //small tables only need a single EMPTY bucket to stop every probe
+    if cap < 8 {
+        return Some((cap + 1).next_power_of_two());
+    }
+    (cap.checked_mul(8)? / 7).checked_next_power_of_two()
+}
+
+//number of elements a table with this many buckets holds before it has to grow
+fn bucket_capacity(buckets: usize) -> usize {
+    if buckets < 8 {
+        buckets.saturating_sub(1)
+    } else {
+        buckets / 8 * 7
+    }
+}
+
+// ===============================
+// RAW TABLE
+// -------------------------------
+// The part of the map that knows nothing about keys or hashers. Every method that needs a hash is
+// handed one, so the same table backs JosieHashMap, JosieHashSet and the index of JosieIndexMap
+// -------------------------------
+
+pub(crate) struct RawTable<T> {
+    ctrl: RawJosieVec<u8>,
+    slots: RawJosieVec<T>,
+    pub(crate) len: usize,
+    //EMPTY buckets that can still be filled before the table is over its load factor. tombstones
+    //count as used until the table is rehashed
+    growth_left: usize,
+}
+
+impl<T> RawTable<T> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            ctrl: RawJosieVec::new_in(Global),
+            slots: RawJosieVec::new_in(Global),
+            len: 0,
+            growth_left: 0,
+        }
+    }
+
+    pub(crate) fn try_with_capacity(cap: usize) -> Result<Self, JosieTryReserveError> {
+        let buckets = capacity_to_buckets(cap).ok_or(JosieTryReserveError::CapacityOverflow)?;
+        Self::try_with_buckets(buckets)
+    }
+
+    //allocates a table with exactly buckets buckets, all of them EMPTY
+    fn try_with_buckets(buckets: usize) -> Result<Self, JosieTryReserveError> {
+        let mut table = Self::new();
+        unsafe {
+            table.ctrl.try_realloc(buckets)?;
+            //every bucket is EMPTY before the slots are allocated, so a table dropped after a failed
+            //allocation has nothing to drop
+            table.ctrl.ptr.as_ptr().write_bytes(EMPTY, buckets);
+            table.slots.try_realloc(buckets)?;
+        }
+        table.growth_left = bucket_capacity(buckets);
+        Ok(table)
+    }
+
+    #[inline(always)]
+    pub(crate) fn buckets(&self) -> usize {
+        self.ctrl.cap
+    }
+
+    #[inline(always)]
+    unsafe fn ctrl(&self, index: usize) -> u8 {
+        unsafe { *self.ctrl.ptr.as_ptr().add(index) }
+    }
+
+    #[inline(always)]
+    unsafe fn set_ctrl(&mut self, index: usize, ctrl: u8) {
+        unsafe { *self.ctrl.ptr.as_ptr().add(index) = ctrl }
+    }
+
+    #[inline(always)]
+    pub(crate) unsafe fn bucket(&self, index: usize) -> *mut T {
+        unsafe { self.slots.ptr.as_ptr().add(index) }
+    }
+
+    fn raw_iter(&self) -> RawIter<T> {
+        RawIter {
+            ctrl: self.ctrl.ptr.as_ptr(),
+            slots: self.slots.ptr.as_ptr(),
+            index: 0,
+            remaining: self.len,
+        }
+    }
+
+    //index of the full bucket whose element eq accepts, or None once the probe reaches an EMPTY bucket
+    pub(crate) fn find(&self, hash: u64, mut eq: impl FnMut(&T) -> bool) -> Option<usize> {
+        //an empty table might not have any buckets at all
+        if self.len == 0 {
+            return None;
+        }
+        let mask = self.buckets() - 1;
+        let tag = h2(hash);
+        let mut index = hash as usize & mask;
+        loop {
+            let ctrl = unsafe { self.ctrl(index) };
+            if ctrl == EMPTY {
+                return None;
+            }
+            if ctrl == tag && eq(unsafe { &*self.bucket(index) }) {
+                return Some(index);
+            }
+            index = (index + 1) & mask;
+        }
+    }
+
+    //first EMPTY or DELETED bucket on the probe of hash. the table must have room for one more element
+    pub(crate) fn find_insert_slot(&self, hash: u64) -> usize {
+        let mask = self.buckets() - 1;
+        let mut index = hash as usize & mask;
+        while is_full(unsafe { self.ctrl(index) }) {
+            index = (index + 1) & mask;
+        }
+        index
+    }
+
+    //writes value into the free bucket at index, which must have come from find_insert_slot
+    pub(crate) unsafe fn insert_in_slot(&mut self, hash: u64, index: usize, value: T) -> *mut T {
+        unsafe {
+            if self.ctrl(index) == EMPTY {
+                self.growth_left -= 1;
+            }
+            self.set_ctrl(index, h2(hash));
+            let bucket = self.bucket(index);
+            bucket.write(value);
+            self.len += 1;
+            bucket
+        }
+    }
+
+    //moves the element out of the full bucket at index
+    pub(crate) unsafe fn remove(&mut self, index: usize) -> T {
+        unsafe {
+            let mask = self.buckets() - 1;
+            //any probe that went through this bucket would stop at the next one if it is EMPTY, so
+            //this one can go back to EMPTY instead of leaving a tombstone behind
+            if self.ctrl((index + 1) & mask) == EMPTY {
+                self.set_ctrl(index, EMPTY);
+                self.growth_left += 1;
+            } else {
+                self.set_ctrl(index, DELETED);
+            }
+            self.len -= 1;
+            self.bucket(index).read()
+        }
+    }
+
+    //moves out the first element at or after index. only used on tables that are being emptied, so
+    //the bucket goes straight back to EMPTY without caring about probes
+    fn take_next(&mut self, index: &mut usize) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        loop {
+            let current = *index;
+            *index += 1;
+            unsafe {
+                if is_full(self.ctrl(current)) {
+                    self.set_ctrl(current, EMPTY);
+                    self.growth_left += 1;
+                    self.len -= 1;
+                    return Some(self.bucket(current).read());
+                }
+            }
+        }
+    }
+
+    //makes sure additional more elements fit without growing, rehashing every element if they dont
+    pub(crate) fn try_reserve(
+        &mut self,
+        additional: usize,
+        hasher: impl Fn(&T) -> u64,
+    ) -> Result<(), JosieTryReserveError> {
+        if additional <= self.growth_left {
+            return Ok(());
+        }
+        let required = self
+            .len
+            .checked_add(additional)
+            .ok_or(JosieTryReserveError::CapacityOverflow)?;
+        let full_capacity = bucket_capacity(self.buckets());
+        //if tombstones are taking up most of the room rehashing at the same size clears them out,
+        //otherwise the bucket count at least doubles
+        let cap = if required <= full_capacity / 2 {
+            full_capacity
+        } else {
+            required.max(full_capacity + 1)
+        };
+        self.try_resize(cap, hasher)
+    }
+
+    //moves every element into a new table with room for cap elements
+    fn try_resize(
+        &mut self,
+        cap: usize,
+        hasher: impl Fn(&T) -> u64,
+    ) -> Result<(), JosieTryReserveError> {
+        let mut new_table = Self::try_with_capacity(cap)?;
+        for index in 0..self.buckets() {
+            unsafe {
+                if !is_full(self.ctrl(index)) {
+                    continue;
+                }
+                //if the hasher panics the elements moved so far drop with new_table and the rest stay here
+                let hash = hasher(&*self.bucket(index));
+                let slot = new_table.find_insert_slot(hash);
+                self.set_ctrl(index, DELETED);
+                self.len -= 1;
+                new_table.insert_in_slot(hash, slot, self.bucket(index).read());
+            }
+        }
+        //the old buffers are freed when new_table drops, every bucket in them is already moved out
+        mem::swap(self, &mut new_table);
+        Ok(())
+    }
+
+    //number of elements the table holds before it has to grow
+    #[inline(always)]
+    pub(crate) fn capacity(&self) -> usize {
+        self.len + self.growth_left
+    }
+
+    //removes and drops every element f returns false for
+    pub(crate) fn retain(&mut self, mut f: impl FnMut(&mut T) -> bool) {
+        for index in 0..self.buckets() {
+            unsafe {
+                if is_full(self.ctrl(index)) && !f(&mut *self.bucket(index)) {
+                    //takes the element out before dropping it so a panicking drop leaves the table valid
+                    drop(self.remove(index));
+                }
+            }
+        }
+    }
+
+    //rehashes into the fewest buckets that hold every element. shrinking is only an optimization so
+    //if the allocator fails the table is just kept as is
+    pub(crate) fn shrink_to_fit(&mut self, hasher: impl Fn(&T) -> u64) {
+        let fewest = capacity_to_buckets(self.len).unwrap_or(usize::MAX);
+        if fewest < self.buckets() {
+            let _ = self.try_resize(self.len, hasher);
+        }
+    }
+
+    //drops every element and marks every bucket EMPTY, keeping the allocation
+    pub(crate) fn clear(&mut self) {
+        for index in 0..self.buckets() {
+            unsafe {
+                if is_full(self.ctrl(index)) {
+                    //tombstones keep every other probe working if a drop panics
+                    self.set_ctrl(index, DELETED);
+                    self.len -= 1;
+                    drop_in_place(self.bucket(index));
+                }
+            }
+        }
+        unsafe { self.ctrl.ptr.as_ptr().write_bytes(EMPTY, self.buckets()) };
+        self.growth_left = bucket_capacity(self.buckets());
+    }
+}
+
+impl<T> Drop for RawTable<T> {
+    fn drop(&mut self) {
+        if needs_drop::<T>() {
+            let mut iter = self.raw_iter();
+            while let Some(bucket) = iter.next_bucket() {
+                unsafe { drop_in_place(bucket) };
+            }
+        }
+        //both buffers are deallocated when ctrl and slots drop
+    }
+}
+
+impl<T: Clone> Clone for RawTable<T> {
+    fn clone(&self) -> Self {
+        let buckets = self.buckets();
+        let mut table =
+            Self::try_with_buckets(buckets).unwrap_or_else(|err| handle_reserve_error(err));
+        unsafe {
+            for index in 0..buckets {
+                let ctrl = self.ctrl(index);
+                if is_full(ctrl) {
+                    //clones into the same bucket so nothing is rehashed. if a clone panics only the
+                    //buckets marked so far are dropped
+                    table.bucket(index).write((*self.bucket(index)).clone());
+                    table.set_ctrl(index, ctrl);
+                    table.len += 1;
+                }
+            }
+            //brings the tombstones over too so every probe runs exactly like it does in self
+            copy_nonoverlapping(self.ctrl.ptr.as_ptr(), table.ctrl.ptr.as_ptr(), buckets);
+        }
+        table.growth_left = self.growth_left;
+        table
+    }
+}
+
+//walks the full buckets of a table, handing out pointers to their elements
+struct RawIter<T> {
+    ctrl: *const u8,
+    slots: *mut T,
+    index: usize,
+    //full buckets left, lets the walk stop at the last element instead of the last bucket
+    remaining: usize,
+}
+
+impl<T> RawIter<T> {
+    fn next_bucket(&mut self) -> Option<*mut T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        loop {
+            let index = self.index;
+            self.index += 1;
+            unsafe {
+                if is_full(*self.ctrl.add(index)) {
+                    self.remaining -= 1;
+                    return Some(self.slots.add(index));
+                }
+            }
+        }
+    }
+}
+
+impl<T> Clone for RawIter<T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+// ===============================
+// JOSIE HASH MAP
+// -------------------------------
+
+///Hash map using open addressing. S builds the hasher, RandomState by default so the bucket of a key
+///cant be predicted from outside
+pub struct JosieHashMap<K, V, S = RandomState> {
+    table: RawTable<(K, V)>,
+    hasher: S,
+}
+
+//Constructors using the default hasher
+impl<K, V> JosieHashMap<K, V> {
+    ///Creates an empty JosieHashMap without allocating
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///Creates an empty JosieHashMap with room for at least cap entries
+    pub fn with_capacity(cap: usize) -> Self {
+        Self::with_capacity_and_hasher(cap, RandomState::new())
+    }
+}
+
+impl<K, V, S> JosieHashMap<K, V, S> {
+    ///Creates an empty JosieHashMap that hashes keys with hasher, without allocating
+    #[inline(always)]
+    pub const fn with_hasher(hasher: S) -> Self {
+        Self {
+            table: RawTable::new(),
+            hasher,
+        }
+    }
+
+    ///Creates an empty JosieHashMap with room for at least cap entries that hashes keys with hasher
+    pub fn with_capacity_and_hasher(cap: usize, hasher: S) -> Self {
+        Self {
+            table: RawTable::try_with_capacity(cap).unwrap_or_else(|err| handle_reserve_error(err)),
+            hasher,
+        }
+    }
+
+    ///Returns the hasher builder of the map
+    #[inline(always)]
+    pub fn hasher(&self) -> &S {
+        &self.hasher
+    }
+
+    ///Number of entries in the map
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.table.len
+    }
+
+    ///Returns true if the map has no entries
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.table.len == 0
+    }
+
+    ///Number of entries that fit before the map has to grow
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.table.capacity()
+    }
+
+    ///Returns an iterator over the entries in bucket order
+    pub fn iter(&self) -> JosieHashMapIterRef<'_, K, V> {
+        JosieHashMapIterRef {
+            iter: self.table.raw_iter(),
+            marker: PhantomData,
+        }
+    }
+
+    ///Returns an iterator over the entries in bucket order with mutable values
+    pub fn iter_mut(&mut self) -> JosieHashMapIterMut<'_, K, V> {
+        JosieHashMapIterMut {
+            iter: self.table.raw_iter(),
+            marker: PhantomData,
+        }
+    }
+
+    ///Returns an iterator over the keys
+    pub fn keys(&self) -> JosieHashMapKeys<'_, K, V> {
+        JosieHashMapKeys { iter: self.iter() }
+    }
+
+    ///Returns an iterator over the values
+    pub fn values(&self) -> JosieHashMapValues<'_, K, V> {
+        JosieHashMapValues { iter: self.iter() }
+    }
+
+    ///Returns an iterator over mutable references to the values
+    pub fn values_mut(&mut self) -> JosieHashMapValuesMut<'_, K, V> {
+        JosieHashMapValuesMut {
+            iter: self.iter_mut(),
+        }
+    }
+
+    ///Removes every entry and returns them, keeping the allocation. Whatever the iterator doesnt yield
+    ///is dropped when it is
+    pub fn drain(&mut self) -> JosieHashMapDrain<'_, K, V> {
+        //the table is moved into the drain so forgetting the drain only leaks it, the map is already empty
+        let table = mem::replace(&mut self.table, RawTable::new());
+        JosieHashMapDrain {
+            home: &mut self.table,
+            table,
+            index: 0,
+        }
+    }
+
+    ///Keeps only the entries the predicate returns true for
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.table.retain(|(key, value)| f(key, value))
+    }
+
+    ///Drops every entry, keeping the allocation
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.table.clear()
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> JosieHashMap<K, V, S> {
+    ///Reserves room for at least additional more entries. Panics on capacity overflow and aborts if the
+    ///allocator fails
+    pub fn reserve(&mut self, additional: usize) {
+        if let Err(err) = self.try_reserve(additional) {
+            handle_reserve_error(err)
+        }
+    }
+
+    ///Reserves room for at least additional more entries, returning an error instead of panicking or
+    ///aborting if the table can not grow. The map is unchanged if it fails
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), JosieTryReserveError> {
+        let hasher = &self.hasher;
+        self.table
+            .try_reserve(additional, |(key, _)| make_hash(hasher, key))
+    }
+
+    ///Shrinks the table to the fewest buckets that still hold every entry
+    pub fn shrink_to_fit(&mut self) {
+        let hasher = &self.hasher;
+        self.table.shrink_to_fit(|(key, _)| make_hash(hasher, key))
+    }
+
+    ///Returns the entry for key, to insert into or change in place
+    pub fn entry(&mut self, key: K) -> JosieHashMapEntry<'_, K, V> {
+        let hash = make_hash(&self.hasher, &key);
+        match self.table.find(hash, |(found, _)| *found == key) {
+            Some(index) => JosieHashMapEntry::Occupied(JosieHashMapOccupiedEntry {
+                table: &mut self.table,
+                index,
+            }),
+            None => {
+                //reserves up front so inserting into the vacant entry never has to rehash
+                self.reserve(1);
+                JosieHashMapEntry::Vacant(JosieHashMapVacantEntry {
+                    table: &mut self.table,
+                    hash,
+                    key,
+                })
+            }
+        }
+    }
+
+    //index of the bucket holding key
+    fn find<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let hash = make_hash(&self.hasher, key);
+        self.table.find(hash, |(found, _)| found.borrow() == key)
+    }
+
+    ///Returns a reference to the value for key
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    ///Returns the stored key and the value for key
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(key)?;
+        let (key, value) = unsafe { &*self.table.bucket(index) };
+        Some((key, value))
+    }
+
+    ///Returns a mutable reference to the value for key
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(key)?;
+        Some(unsafe { &mut (*self.table.bucket(index)).1 })
+    }
+
+    ///Returns true if the map has an entry for key
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).is_some()
+    }
+
+    ///Inserts value for key, returning the value it replaced. The stored key is kept if there was one
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.entry(key) {
+            JosieHashMapEntry::Occupied(mut entry) => Some(entry.insert(value)),
+            JosieHashMapEntry::Vacant(entry) => {
+                entry.insert(value);
+                None
+            }
+        }
+    }
+
+    ///Removes the entry for key and returns its value
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    ///Removes the entry for key and returns the stored key and its value
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(key)?;
+        Some(unsafe { self.table.remove(index) })
+    }
+}
+
+// ===============================
+// ENTRY API
+// -------------------------------
+
+///A view into one entry of a JosieHashMap, returned by JosieHashMap::entry
+pub enum JosieHashMapEntry<'a, K, V> {
+    ///The key is in the map
+    Occupied(JosieHashMapOccupiedEntry<'a, K, V>),
+    ///The key isnt in the map, room for it is already reserved
+    Vacant(JosieHashMapVacantEntry<'a, K, V>),
+}
+
+///An entry whose key is in the map
+pub struct JosieHashMapOccupiedEntry<'a, K, V> {
+    table: &'a mut RawTable<(K, V)>,
+    index: usize,
+}
+
+///An entry whose key isnt in the map yet
+pub struct JosieHashMapVacantEntry<'a, K, V> {
+    table: &'a mut RawTable<(K, V)>,
+    hash: u64,
+    key: K,
+}
+
+impl<'a, K, V> JosieHashMapEntry<'a, K, V> {
+    ///Returns the key of the entry
+    pub fn key(&self) -> &K {
+        match self {
+            Self::Occupied(entry) => entry.key(),
+            Self::Vacant(entry) => entry.key(),
+        }
+    }
+
+    ///Inserts default if the entry is vacant and returns a mutable reference to the value
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    ///Inserts the result of default if the entry is vacant and returns a mutable reference to the value
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        self.or_insert_with_key(|_| default())
+    }
+
+    ///Inserts the result of calling default with the key if the entry is vacant and returns a mutable
+    ///reference to the value
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => {
+                let value = default(&entry.key);
+                entry.insert(value)
+            }
+        }
+    }
+
+    ///Inserts V::default() if the entry is vacant and returns a mutable reference to the value
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    ///Calls f on the value if the entry is occupied
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Self::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K, V> JosieHashMapOccupiedEntry<'a, K, V> {
+    #[inline(always)]
+    fn entry(&self) -> &(K, V) {
+        unsafe { &*self.table.bucket(self.index) }
+    }
+
+    #[inline(always)]
+    fn entry_mut(&mut self) -> &mut (K, V) {
+        unsafe { &mut *self.table.bucket(self.index) }
+    }
+
+    ///Returns the stored key
+    pub fn key(&self) -> &K {
+        &self.entry().0
+    }
+
+    ///Returns the value
+    pub fn get(&self) -> &V {
+        &self.entry().1
+    }
+
+    ///Returns the value mutably
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.entry_mut().1
+    }
+
+    ///Turns the entry into a mutable reference to the value that lives as long as the map borrow
+    pub fn into_mut(self) -> &'a mut V {
+        unsafe { &mut (*self.table.bucket(self.index)).1 }
+    }
+
+    ///Replaces the value, returning the old one
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    ///Removes the entry from the map and returns its value
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    ///Removes the entry from the map and returns the stored key and its value
+    pub fn remove_entry(self) -> (K, V) {
+        unsafe { self.table.remove(self.index) }
+    }
+}
+
+impl<'a, K, V> JosieHashMapVacantEntry<'a, K, V> {
+    ///Returns the key that would be inserted
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    ///Takes the key back without inserting anything
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    ///Inserts value for the key and returns a mutable reference to it
+    pub fn insert(self, value: V) -> &'a mut V {
+        let table = self.table;
+        //JosieHashMap::entry reserved room for this insert
+        let slot = table.find_insert_slot(self.hash);
+        unsafe { &mut (*table.insert_in_slot(self.hash, slot, (self.key, value))).1 }
+    }
+}
+
+// ===============================
+// ITERATORS
+// -------------------------------
+
+///Iterator over references to the entries of a JosieHashMap
+pub struct JosieHashMapIterRef<'a, K, V> {
+    iter: RawIter<(K, V)>,
+    marker: PhantomData<&'a (K, V)>,
+}
+
+impl<'a, K, V> Iterator for JosieHashMapIterRef<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = unsafe { &*self.iter.next_bucket()? };
+        Some((key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.iter.remaining, Some(self.iter.remaining))
+    }
+}
+
+impl<K, V> Clone for JosieHashMapIterRef<'_, K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+///Iterator over the entries of a JosieHashMap with mutable values
+pub struct JosieHashMapIterMut<'a, K, V> {
+    iter: RawIter<(K, V)>,
+    marker: PhantomData<&'a mut (K, V)>,
+}
+
+impl<'a, K, V> Iterator for JosieHashMapIterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = unsafe { &mut *self.iter.next_bucket()? };
+        Some((key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.iter.remaining, Some(self.iter.remaining))
+    }
+}
+
+///Iterator over the keys of a JosieHashMap
+#[derive(Clone)]
+pub struct JosieHashMapKeys<'a, K, V> {
+    iter: JosieHashMapIterRef<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for JosieHashMapKeys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<&'a K> {
+        self.iter.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+///Iterator over the values of a JosieHashMap
+#[derive(Clone)]
+pub struct JosieHashMapValues<'a, K, V> {
+    iter: JosieHashMapIterRef<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for JosieHashMapValues<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<&'a V> {
+        self.iter.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+///Iterator over mutable references to the values of a JosieHashMap
+pub struct JosieHashMapValuesMut<'a, K, V> {
+    iter: JosieHashMapIterMut<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for JosieHashMapValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    fn next(&mut self) -> Option<&'a mut V> {
+        self.iter.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+///Owning iterator over the entries of a JosieHashMap
+pub struct JosieHashMapIter<K, V> {
+    table: RawTable<(K, V)>,
+    index: usize,
+}
+
+impl<K, V> Iterator for JosieHashMapIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        self.table.take_next(&mut self.index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.table.len, Some(self.table.len))
+    }
+}
+
+///Draining iterator for JosieHashMap, returned by JosieHashMap::drain
+pub struct JosieHashMapDrain<'a, K, V> {
+    //where the table goes back to once it is empty
+    home: &'a mut RawTable<(K, V)>,
+    table: RawTable<(K, V)>,
+    index: usize,
+}
+
+impl<K, V> Iterator for JosieHashMapDrain<'_, K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        self.table.take_next(&mut self.index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.table.len, Some(self.table.len))
+    }
+}
+
+impl<K, V> Drop for JosieHashMapDrain<'_, K, V> {
+    fn drop(&mut self) {
+        self.table.clear();
+        //hands the emptied allocation back to the map
+        mem::swap(self.home, &mut self.table);
+    }
+}
+
+impl<K, V> ExactSizeIterator for JosieHashMapIterRef<'_, K, V> {}
+impl<K, V> ExactSizeIterator for JosieHashMapIterMut<'_, K, V> {}
+impl<K, V> ExactSizeIterator for JosieHashMapKeys<'_, K, V> {}
+impl<K, V> ExactSizeIterator for JosieHashMapValues<'_, K, V> {}
+impl<K, V> ExactSizeIterator for JosieHashMapValuesMut<'_, K, V> {}
+impl<K, V> ExactSizeIterator for JosieHashMapIter<K, V> {}
+impl<K, V> ExactSizeIterator for JosieHashMapDrain<'_, K, V> {}
+
+impl<K, V> FusedIterator for JosieHashMapIterRef<'_, K, V> {}
+impl<K, V> FusedIterator for JosieHashMapIterMut<'_, K, V> {}
+impl<K, V> FusedIterator for JosieHashMapKeys<'_, K, V> {}
+impl<K, V> FusedIterator for JosieHashMapValues<'_, K, V> {}
+impl<K, V> FusedIterator for JosieHashMapValuesMut<'_, K, V> {}
+impl<K, V> FusedIterator for JosieHashMapIter<K, V> {}
+impl<K, V> FusedIterator for JosieHashMapDrain<'_, K, V> {}
+
+impl<K, V, S> IntoIterator for JosieHashMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = JosieHashMapIter<K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        JosieHashMapIter {
+            table: self.table,
+            index: 0,
+        }
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a JosieHashMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = JosieHashMapIterRef<'a, K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a mut JosieHashMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = JosieHashMapIterMut<'a, K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+// ===============================
+// TRAITS
+// -------------------------------
+
+impl<K, V, S: Default> Default for JosieHashMap<K, V, S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K: Clone, V: Clone, S: Clone> Clone for JosieHashMap<K, V, S> {
+    fn clone(&self) -> Self {
+        Self {
+            table: self.table.clone(),
+            hasher: self.hasher.clone(),
+        }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, S> fmt::Debug for JosieHashMap<K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+//two maps are equal if they have the same keys mapped to equal values, whatever order they are stored in
+impl<K: Eq + Hash, V: PartialEq, S: BuildHasher> PartialEq for JosieHashMap<K, V, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key).is_some_and(|other| *value == *other))
+    }
+}
+
+impl<K: Eq + Hash, V: Eq, S: BuildHasher> Eq for JosieHashMap<K, V, S> {}
+
+impl<K, Q, V, S> Index<&Q> for JosieHashMap<K, V, S>
+where
+    K: Eq + Hash + Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+    S: BuildHasher,
+{
+    type Output = V;
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("Key not found in JosieHashMap")
+    }
+}
+
+//how many entries to reserve before extending a map with an iterator whose lower size hint is hint. duplicate
+//keys dont take up room, so a map that already has entries only reserves for half
+pub(crate) fn extend_reserve(is_empty: bool, hint: usize) -> usize {
+    if is_empty {
+        hint
+    } else {
+        hint.div_ceil(2)
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> Extend<(K, V)> for JosieHashMap<K, V, S> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let iterator = iter.into_iter();
+        self.reserve(extend_reserve(self.is_empty(), iterator.size_hint().0));
+        for (key, value) in iterator {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<'a, K: Eq + Hash + Copy, V: Copy, S: BuildHasher> Extend<(&'a K, &'a V)>
+    for JosieHashMap<K, V, S>
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (&'a K, &'a V)>,
+    {
+        self.extend(iter.into_iter().map(|(&key, &value)| (key, value)))
+    }
+}
+
+impl<K: Eq + Hash, V> FromIterator<(K, V)> for JosieHashMap<K, V> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut temp = Self::new();
+        temp.extend(iter);
+        temp
+    }
+}
+
+impl<K: Eq + Hash, V, const N: usize> From<[(K, V); N]> for JosieHashMap<K, V> {
+    fn from(array: [(K, V); N]) -> Self {
+        Self::from_iter(array)
+    }
+}
@@ -0,0 +1,456 @@
+// ===============================
+//       JOSIE HASH SET
+// -------------------------------
+// Hash set stored as a JosieHashMap with () values, so it shares the table, the hashing and the
+// growth of the map and adds the set operations on top
+// -------------------------------
+
+use std::{
+    borrow::Borrow,
+    fmt,
+    hash::{BuildHasher, Hash, RandomState},
+    iter::{Chain, FusedIterator},
+};
+
+use crate::{
+    josie_hash_map::{JosieHashMap, JosieHashMapDrain, JosieHashMapIter, JosieHashMapKeys},
+    josie_vec::josievec_error::JosieTryReserveError,
+};
+
+///Hash set using open addressing. S builds the hasher, RandomState by default
+pub struct JosieHashSet<T, S = RandomState> {
+    map: JosieHashMap<T, (), S>,
+}
+
+//Constructors using the default hasher
+impl<T> JosieHashSet<T> {
+    ///Creates an empty JosieHashSet without allocating
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///Creates an empty JosieHashSet with room for at least cap elements
+    pub fn with_capacity(cap: usize) -> Self {
+        Self::with_capacity_and_hasher(cap, RandomState::new())
+    }
+}
+
+impl<T, S> JosieHashSet<T, S> {
+    ///Creates an empty JosieHashSet that hashes elements with hasher, without allocating
+    #[inline(always)]
+    pub const fn with_hasher(hasher: S) -> Self {
+        Self {
+            map: JosieHashMap::with_hasher(hasher),
+        }
+    }
+
+    ///Creates an empty JosieHashSet with room for at least cap elements that hashes elements with hasher
+    pub fn with_capacity_and_hasher(cap: usize, hasher: S) -> Self {
+        Self {
+            map: JosieHashMap::with_capacity_and_hasher(cap, hasher),
+        }
+    }
+
+    ///Returns the hasher builder of the set
+    #[inline(always)]
+    pub fn hasher(&self) -> &S {
+        self.map.hasher()
+    }
+
+    ///Number of elements in the set
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    ///Returns true if the set has no elements
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    ///Number of elements that fit before the set has to grow
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    ///Returns an iterator over the elements in bucket order
+    pub fn iter(&self) -> JosieHashSetIterRef<'_, T> {
+        JosieHashSetIterRef {
+            iter: self.map.keys(),
+        }
+    }
+
+    ///Removes every element and returns them, keeping the allocation
+    pub fn drain(&mut self) -> JosieHashSetDrain<'_, T> {
+        JosieHashSetDrain {
+            iter: self.map.drain(),
+        }
+    }
+
+    ///Keeps only the elements the predicate returns true for
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.map.retain(|element, _| f(element))
+    }
+
+    ///Drops every element, keeping the allocation
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+}
+
+impl<T: Eq + Hash, S: BuildHasher> JosieHashSet<T, S> {
+    ///Reserves room for at least additional more elements
+    #[inline(always)]
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional)
+    }
+
+    ///Reserves room for at least additional more elements, returning an error instead of panicking or
+    ///aborting if the table can not grow
+    #[inline(always)]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), JosieTryReserveError> {
+        self.map.try_reserve(additional)
+    }
+
+    ///Shrinks the table to the fewest buckets that still hold every element
+    #[inline(always)]
+    pub fn shrink_to_fit(&mut self) {
+        self.map.shrink_to_fit()
+    }
+
+    ///Returns true if the set holds value
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(value)
+    }
+
+    ///Returns the stored element equal to value
+    pub fn get<Q>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get_key_value(value).map(|(element, _)| element)
+    }
+
+    ///Adds value to the set, returning false if it was already there. The stored element is kept
+    pub fn insert(&mut self, value: T) -> bool {
+        self.map.insert(value, ()).is_none()
+    }
+
+    ///Adds value to the set, returning the stored element it replaced
+    pub fn replace(&mut self, value: T) -> Option<T> {
+        let old = self.take(&value);
+        self.map.insert(value, ());
+        old
+    }
+
+    ///Removes value from the set, returning true if it was there
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove(value).is_some()
+    }
+
+    ///Removes value from the set and returns the stored element
+    pub fn take<Q>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove_entry(value).map(|(element, _)| element)
+    }
+
+    ///Returns the elements of self that arent in other
+    pub fn difference<'a>(&'a self, other: &'a Self) -> JosieHashSetDifference<'a, T, S> {
+        JosieHashSetDifference {
+            iter: self.iter(),
+            other,
+        }
+    }
+
+    ///Returns the elements in both self and other
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> JosieHashSetIntersection<'a, T, S> {
+        //walks the smaller set and looks each element up in the bigger one
+        let (small, large) = if self.len() <= other.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        JosieHashSetIntersection {
+            iter: small.iter(),
+            other: large,
+        }
+    }
+
+    ///Returns the elements in self or other, each once
+    pub fn union<'a>(&'a self, other: &'a Self) -> JosieHashSetUnion<'a, T, S> {
+        JosieHashSetUnion {
+            iter: self.iter().chain(other.difference(self)),
+        }
+    }
+
+    ///Returns the elements in exactly one of self and other
+    pub fn symmetric_difference<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> JosieHashSetSymmetricDifference<'a, T, S> {
+        JosieHashSetSymmetricDifference {
+            iter: self.difference(other).chain(other.difference(self)),
+        }
+    }
+
+    ///Returns true if no element is in both sets
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).next().is_none()
+    }
+
+    ///Returns true if every element of self is in other
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.len() <= other.len() && self.iter().all(|element| other.contains(element))
+    }
+
+    ///Returns true if every element of other is in self
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+}
+
+// ===============================
+// ITERATORS
+// -------------------------------
+
+///Iterator over references to the elements of a JosieHashSet
+#[derive(Clone)]
+pub struct JosieHashSetIterRef<'a, T> {
+    iter: JosieHashMapKeys<'a, T, ()>,
+}
+
+impl<'a, T> Iterator for JosieHashSetIterRef<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+///Owning iterator over the elements of a JosieHashSet
+pub struct JosieHashSetIter<T> {
+    iter: JosieHashMapIter<T, ()>,
+}
+
+impl<T> Iterator for JosieHashSetIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.iter.next().map(|(element, _)| element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+///Draining iterator for JosieHashSet, returned by JosieHashSet::drain
+pub struct JosieHashSetDrain<'a, T> {
+    iter: JosieHashMapDrain<'a, T, ()>,
+}
+
+impl<T> Iterator for JosieHashSetDrain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.iter.next().map(|(element, _)| element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for JosieHashSetIterRef<'_, T> {}
+impl<T> ExactSizeIterator for JosieHashSetIter<T> {}
+impl<T> ExactSizeIterator for JosieHashSetDrain<'_, T> {}
+
+impl<T> FusedIterator for JosieHashSetIterRef<'_, T> {}
+impl<T> FusedIterator for JosieHashSetIter<T> {}
+impl<T> FusedIterator for JosieHashSetDrain<'_, T> {}
+
+///Iterator over the elements of one set that arent in another, returned by JosieHashSet::difference
+pub struct JosieHashSetDifference<'a, T, S = RandomState> {
+    iter: JosieHashSetIterRef<'a, T>,
+    other: &'a JosieHashSet<T, S>,
+}
+
+impl<'a, T: Eq + Hash, S: BuildHasher> Iterator for JosieHashSetDifference<'a, T, S> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let other = self.other;
+        self.iter.find(|element| !other.contains(*element))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+///Iterator over the elements in both of two sets, returned by JosieHashSet::intersection
+pub struct JosieHashSetIntersection<'a, T, S = RandomState> {
+    iter: JosieHashSetIterRef<'a, T>,
+    other: &'a JosieHashSet<T, S>,
+}
+
+impl<'a, T: Eq + Hash, S: BuildHasher> Iterator for JosieHashSetIntersection<'a, T, S> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let other = self.other;
+        self.iter.find(|element| other.contains(*element))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+///Iterator over the elements in either of two sets, returned by JosieHashSet::union
+pub struct JosieHashSetUnion<'a, T, S = RandomState> {
+    iter: Chain<JosieHashSetIterRef<'a, T>, JosieHashSetDifference<'a, T, S>>,
+}
+
+impl<'a, T: Eq + Hash, S: BuildHasher> Iterator for JosieHashSetUnion<'a, T, S> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+///Iterator over the elements in exactly one of two sets, returned by JosieHashSet::symmetric_difference
+pub struct JosieHashSetSymmetricDifference<'a, T, S = RandomState> {
+    iter: Chain<JosieHashSetDifference<'a, T, S>, JosieHashSetDifference<'a, T, S>>,
+}
+
+impl<'a, T: Eq + Hash, S: BuildHasher> Iterator for JosieHashSetSymmetricDifference<'a, T, S> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T: Eq + Hash, S: BuildHasher> FusedIterator for JosieHashSetDifference<'_, T, S> {}
+impl<T: Eq + Hash, S: BuildHasher> FusedIterator for JosieHashSetIntersection<'_, T, S> {}
+impl<T: Eq + Hash, S: BuildHasher> FusedIterator for JosieHashSetUnion<'_, T, S> {}
+impl<T: Eq + Hash, S: BuildHasher> FusedIterator for JosieHashSetSymmetricDifference<'_, T, S> {}
+
+impl<T, S> IntoIterator for JosieHashSet<T, S> {
+    type Item = T;
+    type IntoIter = JosieHashSetIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        JosieHashSetIter {
+            iter: self.map.into_iter(),
+        }
+    }
+}
+
+impl<'a, T, S> IntoIterator for &'a JosieHashSet<T, S> {
+    type Item = &'a T;
+    type IntoIter = JosieHashSetIterRef<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// ===============================
+// TRAITS
+// -------------------------------
+
+impl<T, S: Default> Default for JosieHashSet<T, S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<T: Clone, S: Clone> Clone for JosieHashSet<T, S> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug, S> fmt::Debug for JosieHashSet<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Eq + Hash, S: BuildHasher> PartialEq for JosieHashSet<T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.is_subset(other)
+    }
+}
+
+impl<T: Eq + Hash, S: BuildHasher> Eq for JosieHashSet<T, S> {}
+
+impl<T: Eq + Hash, S: BuildHasher> Extend<T> for JosieHashSet<T, S> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.map
+            .extend(iter.into_iter().map(|element| (element, ())))
+    }
+}
+
+impl<'a, T: Eq + Hash + Copy, S: BuildHasher> Extend<&'a T> for JosieHashSet<T, S> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = &'a T>,
+    {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+impl<T: Eq + Hash> FromIterator<T> for JosieHashSet<T> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut temp = Self::new();
+        temp.extend(iter);
+        temp
+    }
+}
+
+impl<T: Eq + Hash, const N: usize> From<[T; N]> for JosieHashSet<T> {
+    fn from(array: [T; N]) -> Self {
+        Self::from_iter(array)
+    }
+}
@@ -0,0 +1,696 @@
+// ===============================
+//       JOSIE INDEX MAP
+// -------------------------------
+// Hash map that keeps its entries in insertion order. The entries live densely in a josievec and a
+// RawTable of positions into that josievec does the hashing, so lookups stay O(1) while iteration
+// walks the josievec front to back. Removing moves the entries with the josievec's own swap_remove,
+// remove and drain and then fixes up the positions of the entries that moved
+// -------------------------------
+
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt,
+    hash::{BuildHasher, Hash, RandomState},
+    iter::FusedIterator,
+    mem::forget,
+    ops::{Index, IndexMut, Range, RangeBounds},
+    slice,
+};
+
+use crate::{
+    entry_iterator,
+    josie_hash_map::{extend_reserve, make_hash, RawTable},
+    josie_vec::{
+        handle_reserve_error, josievec_alloc::Global, josievec_drain::JosieVecDrain,
+        josievec_error::JosieTryReserveError, josievec_iter::JosieVecIter, range_from_bounds,
+        JosieVec,
+    },
+};
+
+///Hash map that iterates in insertion order and can be indexed by position
+pub struct JosieIndexMap<K, V, S = RandomState> {
+    entries: JosieVec<(K, V)>,
+    //position of every entry in entries, hashed by the key of that entry
+    indices: RawTable<usize>,
+    hasher: S,
+}
+
+//Constructors using the default hasher
+impl<K, V> JosieIndexMap<K, V> {
+    ///Creates an empty JosieIndexMap without allocating
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///Creates an empty JosieIndexMap with room for at least cap entries
+    pub fn with_capacity(cap: usize) -> Self {
+        Self::with_capacity_and_hasher(cap, RandomState::new())
+    }
+}
+
+impl<K, V, S> JosieIndexMap<K, V, S> {
+    ///Creates an empty JosieIndexMap that hashes keys with hasher, without allocating
+    #[inline(always)]
+    pub const fn with_hasher(hasher: S) -> Self {
+        Self {
+            entries: JosieVec::new_in(Global),
+            indices: RawTable::new(),
+            hasher,
+        }
+    }
+
+    ///Creates an empty JosieIndexMap with room for at least cap entries that hashes keys with hasher
+    pub fn with_capacity_and_hasher(cap: usize, hasher: S) -> Self {
+        Self {
+            entries: JosieVec::with_capacity(cap),
+            indices: RawTable::try_with_capacity(cap)
+                .unwrap_or_else(|err| handle_reserve_error(err)),
+            hasher,
+        }
+    }
+
+    ///Returns the hasher builder of the map
+    #[inline(always)]
+    pub fn hasher(&self) -> &S {
+        &self.hasher
+    }
+
+    ///Number of entries in the map
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    ///Returns true if the map has no entries
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    ///Number of entries that fit before either the entries or the index have to grow
+    pub fn capacity(&self) -> usize {
+        self.entries.capacity().min(self.indices.capacity())
+    }
+
+    ///Returns the entries in order as a slice
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[(K, V)] {
+        self.entries.as_slice()
+    }
+
+    ///Returns an iterator over the entries in order
+    pub fn iter(&self) -> JosieIndexMapIterRef<'_, K, V> {
+        JosieIndexMapIterRef {
+            iter: self.entries.as_slice().iter(),
+        }
+    }
+
+    ///Returns an iterator over the entries in order with mutable values
+    pub fn iter_mut(&mut self) -> JosieIndexMapIterMut<'_, K, V> {
+        JosieIndexMapIterMut {
+            iter: self.entries.as_mut_slice().iter_mut(),
+        }
+    }
+
+    ///Returns an iterator over the keys in order
+    pub fn keys(&self) -> JosieIndexMapKeys<'_, K, V> {
+        JosieIndexMapKeys {
+            iter: self.entries.as_slice().iter(),
+        }
+    }
+
+    ///Returns an iterator over the values in order
+    pub fn values(&self) -> JosieIndexMapValues<'_, K, V> {
+        JosieIndexMapValues {
+            iter: self.entries.as_slice().iter(),
+        }
+    }
+
+    ///Returns an iterator over mutable references to the values in order
+    pub fn values_mut(&mut self) -> JosieIndexMapValuesMut<'_, K, V> {
+        JosieIndexMapValuesMut {
+            iter: self.entries.as_mut_slice().iter_mut(),
+        }
+    }
+
+    ///Returns the entry at position index
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        let (key, value) = self.entries.get(index)?;
+        Some((key, value))
+    }
+
+    ///Returns the entry at position index with a mutable value
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        let (key, value) = self.entries.get_mut(index)?;
+        Some((key, value))
+    }
+
+    ///Returns the first entry
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.get_index(0)
+    }
+
+    ///Returns the last entry
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.get_index(self.len().checked_sub(1)?)
+    }
+
+    ///Removes the entry at position index, moving every entry after it down one. O(n)
+    pub fn shift_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        if index >= self.len() {
+            return None;
+        }
+        self.indices
+            .retain(|position| match (*position).cmp(&index) {
+                Ordering::Less => true,
+                Ordering::Equal => false,
+                Ordering::Greater => {
+                    *position -= 1;
+                    true
+                }
+            });
+        Some(self.entries.remove(index))
+    }
+
+    ///Drops every entry, keeping the allocations
+    pub fn clear(&mut self) {
+        self.indices.clear();
+        self.entries.clear();
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> JosieIndexMap<K, V, S> {
+    ///Reserves room for at least additional more entries
+    pub fn reserve(&mut self, additional: usize) {
+        if let Err(err) = self.try_reserve(additional) {
+            handle_reserve_error(err)
+        }
+    }
+
+    ///Reserves room for at least additional more entries, returning an error instead of panicking or
+    ///aborting if the entries or the index can not grow
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), JosieTryReserveError> {
+        let (hasher, entries) = (&self.hasher, &self.entries);
+        self.indices.try_reserve(additional, |&position| {
+            make_hash(hasher, &entries[position].0)
+        })?;
+        self.entries.try_reserve(additional)
+    }
+
+    ///Shrinks the entries and the index to fit the entries there are
+    pub fn shrink_to_fit(&mut self) {
+        let (hasher, entries) = (&self.hasher, &self.entries);
+        self.indices
+            .shrink_to_fit(|&position| make_hash(hasher, &entries[position].0));
+        self.entries.shrink_to_fit();
+    }
+
+    //bucket of the index holding key and the position of the entry
+    fn find<Q>(&self, key: &Q) -> Option<(usize, usize)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let hash = make_hash(&self.hasher, key);
+        let entries = &self.entries;
+        let bucket = self
+            .indices
+            .find(hash, |&position| entries[position].0.borrow() == key)?;
+        Some((bucket, unsafe { *self.indices.bucket(bucket) }))
+    }
+
+    //bucket of the index pointing at the entry at position
+    fn find_position(&self, position: usize) -> usize {
+        let hash = make_hash(&self.hasher, &self.entries[position].0);
+        self.indices
+            .find(hash, |&found| found == position)
+            .expect("every entry has a position in the index")
+    }
+
+    //hashes every entry into the index again after the entries were moved around
+    fn rebuild_indices(&mut self) {
+        self.indices.clear();
+        index_from(&mut self.indices, &self.hasher, &self.entries, 0);
+    }
+
+    ///Returns the position of the entry for key
+    pub fn get_index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).map(|(_, position)| position)
+    }
+
+    ///Returns the position, stored key and value of the entry for key
+    pub fn get_full<Q>(&self, key: &Q) -> Option<(usize, &K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (_, position) = self.find(key)?;
+        let (key, value) = &self.entries[position];
+        Some((position, key, value))
+    }
+
+    ///Returns a reference to the value for key
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_full(key).map(|(_, _, value)| value)
+    }
+
+    ///Returns the stored key and the value for key
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_full(key).map(|(_, key, value)| (key, value))
+    }
+
+    ///Returns a mutable reference to the value for key
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (_, position) = self.find(key)?;
+        Some(&mut self.entries[position].1)
+    }
+
+    ///Returns true if the map has an entry for key
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).is_some()
+    }
+
+    ///Inserts value for key, returning the value it replaced. A new key goes at the end, an existing
+    ///key keeps its position
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.insert_full(key, value).1
+    }
+
+    ///Inserts value for key, returning the position of the entry and the value it replaced
+    pub fn insert_full(&mut self, key: K, value: V) -> (usize, Option<V>) {
+        if let Some((_, position)) = self.find(&key) {
+            let old = std::mem::replace(&mut self.entries[position].1, value);
+            return (position, Some(old));
+        }
+        self.reserve(1);
+        let hash = make_hash(&self.hasher, &key);
+        let position = self.len();
+        let slot = self.indices.find_insert_slot(hash);
+        unsafe { self.indices.insert_in_slot(hash, slot, position) };
+        self.entries.push((key, value));
+        (position, None)
+    }
+
+    ///Removes the entry for key by swapping the last entry into its place. O(1) but changes the order
+    pub fn swap_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.swap_remove_full(key).map(|(_, _, value)| value)
+    }
+
+    ///Like swap_remove but also returns the position the entry was at and the stored key
+    pub fn swap_remove_full<Q>(&mut self, key: &Q) -> Option<(usize, K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let position = self.get_index_of(key)?;
+        let (key, value) = self.swap_remove_index(position)?;
+        Some((position, key, value))
+    }
+
+    ///Removes the entry at position index by swapping the last entry into its place. O(1)
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        if index >= self.len() {
+            return None;
+        }
+        let bucket = self.find_position(index);
+        unsafe { self.indices.remove(bucket) };
+        let last = self.len() - 1;
+        if index != last {
+            //the last entry is about to move into index
+            let moved = self.find_position(last);
+            unsafe { *self.indices.bucket(moved) = index };
+        }
+        Some(self.entries.swap_remove(index))
+    }
+
+    ///Removes the entry for key, moving every entry after it down one so the order is kept. O(n)
+    pub fn shift_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.shift_remove_full(key).map(|(_, _, value)| value)
+    }
+
+    ///Like shift_remove but also returns the position the entry was at and the stored key
+    pub fn shift_remove_full<Q>(&mut self, key: &Q) -> Option<(usize, K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let position = self.get_index_of(key)?;
+        let (key, value) = self.shift_remove_index(position)?;
+        Some((position, key, value))
+    }
+
+    ///Removes and returns the last entry
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        self.swap_remove_index(self.len().checked_sub(1)?)
+    }
+
+    ///Removes the entries in range and returns them in order, moving the entries after it down
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> JosieIndexMapDrain<'_, K, V, S> {
+        let Range { start, end } = range_from_bounds(range, self.len());
+        //drops the positions of the drained entries and of the tail up front, so if the drain is leaked the
+        //index still matches the entries the josievec is cut down to. the tail is hashed back in once the
+        //josievec drain has moved it
+        self.indices.retain(|position| *position < start);
+        JosieIndexMapDrain {
+            iter: self.entries.drain(start..end),
+            indices: &mut self.indices,
+            hasher: &self.hasher,
+            start,
+        }
+    }
+
+    ///Keeps only the entries the predicate returns true for, in the order they were in
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        let len = self.len();
+        let guard = RebuildIndicesGuard { map: self };
+        guard.map.entries.retain_mut(|(key, value)| f(key, value));
+        if guard.map.len() == len {
+            //nothing was removed so every position is still right
+            forget(guard);
+        }
+    }
+
+    ///Sorts the entries by key
+    pub fn sort_keys(&mut self)
+    where
+        K: Ord,
+    {
+        self.sort_by(|key_a, _, key_b, _| key_a.cmp(key_b));
+    }
+
+    ///Sorts the entries with a comparison over both keys and values. The sort is stable
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        let guard = RebuildIndicesGuard { map: self };
+        guard
+            .map
+            .entries
+            .sort_by(|(key_a, value_a), (key_b, value_b)| compare(key_a, value_a, key_b, value_b));
+    }
+}
+
+//raii guard for the methods that move entries around inside the josievec, hashes every entry into the index
+//again once they are done, or while unwinding if the predicate or comparison panicked halfway through
+struct RebuildIndicesGuard<'a, K: Eq + Hash, V, S: BuildHasher> {
+    map: &'a mut JosieIndexMap<K, V, S>,
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> Drop for RebuildIndicesGuard<'_, K, V, S> {
+    fn drop(&mut self) {
+        self.map.rebuild_indices();
+    }
+}
+
+//hashes the entries from position start on into indices, which must only hold positions before start
+fn index_from<K: Hash, V, S: BuildHasher>(
+    indices: &mut RawTable<usize>,
+    hasher: &S,
+    entries: &[(K, V)],
+    start: usize,
+) {
+    let reserved = indices.try_reserve(entries.len() - start, |&position| {
+        make_hash(hasher, &entries[position].0)
+    });
+    if let Err(err) = reserved {
+        handle_reserve_error(err)
+    }
+    for (position, (key, _)) in entries.iter().enumerate().skip(start) {
+        let hash = make_hash(hasher, key);
+        let slot = indices.find_insert_slot(hash);
+        unsafe { indices.insert_in_slot(hash, slot, position) };
+    }
+}
+
+// ===============================
+// ITERATORS
+// -------------------------------
+
+///Iterator over references to the entries of a JosieIndexMap, in order
+#[derive(Clone)]
+pub struct JosieIndexMapIterRef<'a, K, V> {
+    iter: slice::Iter<'a, (K, V)>,
+}
+
+entry_iterator!(JosieIndexMapIterRef<'a, K, V>, (&'a K, &'a V), |(key, value)| (key, value));
+
+///Iterator over the entries of a JosieIndexMap with mutable values, in order
+pub struct JosieIndexMapIterMut<'a, K, V> {
+    iter: slice::IterMut<'a, (K, V)>,
+}
+
+entry_iterator!(JosieIndexMapIterMut<'a, K, V>, (&'a K, &'a mut V), |(key, value)| (&*key, value));
+
+///Iterator over the keys of a JosieIndexMap, in order
+#[derive(Clone)]
+pub struct JosieIndexMapKeys<'a, K, V> {
+    iter: slice::Iter<'a, (K, V)>,
+}
+
+entry_iterator!(JosieIndexMapKeys<'a, K, V>, &'a K, |(key, _)| key);
+
+///Iterator over the values of a JosieIndexMap, in order
+#[derive(Clone)]
+pub struct JosieIndexMapValues<'a, K, V> {
+    iter: slice::Iter<'a, (K, V)>,
+}
+
+entry_iterator!(JosieIndexMapValues<'a, K, V>, &'a V, |(_, value)| value);
+
+///Iterator over mutable references to the values of a JosieIndexMap, in order
+pub struct JosieIndexMapValuesMut<'a, K, V> {
+    iter: slice::IterMut<'a, (K, V)>,
+}
+
+entry_iterator!(JosieIndexMapValuesMut<'a, K, V>, &'a mut V, |(_, value)| value);
+
+///Owning iterator over the entries of a JosieIndexMap, in order
+pub struct JosieIndexMapIter<K, V> {
+    iter: JosieVecIter<(K, V)>,
+}
+
+entry_iterator!(JosieIndexMapIter<K, V>, (K, V), |entry| entry);
+
+///Draining iterator for JosieIndexMap, returned by JosieIndexMap::drain
+pub struct JosieIndexMapDrain<'a, K: Eq + Hash, V, S: BuildHasher = RandomState> {
+    iter: JosieVecDrain<'a, (K, V)>,
+    indices: &'a mut RawTable<usize>,
+    hasher: &'a S,
+    //where the tail starts once it has been moved back
+    start: usize,
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> Iterator for JosieIndexMapDrain<'_, K, V, S> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> DoubleEndedIterator for JosieIndexMapDrain<'_, K, V, S> {
+    fn next_back(&mut self) -> Option<(K, V)> {
+        self.iter.next_back()
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> ExactSizeIterator for JosieIndexMapDrain<'_, K, V, S> {}
+impl<K: Eq + Hash, V, S: BuildHasher> FusedIterator for JosieIndexMapDrain<'_, K, V, S> {}
+
+impl<K: Eq + Hash, V, S: BuildHasher> Drop for JosieIndexMapDrain<'_, K, V, S> {
+    fn drop(&mut self) {
+        let guard = DrainIndexTailGuard { drain: self };
+        guard.drain.iter.finish();
+    }
+}
+
+//raii guard that hashes the tail back into the index once the josievec drain has moved it back, even if
+//dropping one of the drained entries panics
+struct DrainIndexTailGuard<'r, 'a, K: Eq + Hash, V, S: BuildHasher> {
+    drain: &'r mut JosieIndexMapDrain<'a, K, V, S>,
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> Drop for DrainIndexTailGuard<'_, '_, K, V, S> {
+    fn drop(&mut self) {
+        let drain = &mut *self.drain;
+        index_from(drain.indices, drain.hasher, drain.iter.josievec, drain.start);
+    }
+}
+
+impl<K, V, S> IntoIterator for JosieIndexMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = JosieIndexMapIter<K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        JosieIndexMapIter {
+            iter: self.entries.into_iter(),
+        }
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a JosieIndexMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = JosieIndexMapIterRef<'a, K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a mut JosieIndexMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = JosieIndexMapIterMut<'a, K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+// ===============================
+// TRAITS
+// -------------------------------
+
+impl<K, V, S: Default> Default for JosieIndexMap<K, V, S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K: Clone, V: Clone, S: Clone> Clone for JosieIndexMap<K, V, S> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+            indices: self.indices.clone(),
+            hasher: self.hasher.clone(),
+        }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, S> fmt::Debug for JosieIndexMap<K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+//equal if they map the same keys to equal values, the order of the entries doesnt matter
+impl<K: Eq + Hash, V: PartialEq, S: BuildHasher> PartialEq for JosieIndexMap<K, V, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key).is_some_and(|other| *value == *other))
+    }
+}
+
+impl<K: Eq + Hash, V: Eq, S: BuildHasher> Eq for JosieIndexMap<K, V, S> {}
+
+impl<K, Q, V, S> Index<&Q> for JosieIndexMap<K, V, S>
+where
+    K: Eq + Hash + Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+    S: BuildHasher,
+{
+    type Output = V;
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("Key not found in JosieIndexMap")
+    }
+}
+
+impl<K, V, S> Index<usize> for JosieIndexMap<K, V, S> {
+    type Output = V;
+    fn index(&self, index: usize) -> &V {
+        match self.entries.get(index) {
+            Some((_, value)) => value,
+            None => panic!(
+                "Index {index} is out of range for JosieIndexMap of length {}",
+                self.len()
+            ),
+        }
+    }
+}
+
+impl<K, V, S> IndexMut<usize> for JosieIndexMap<K, V, S> {
+    fn index_mut(&mut self, index: usize) -> &mut V {
+        let len = self.len();
+        match self.entries.get_mut(index) {
+            Some((_, value)) => value,
+            None => panic!("Index {index} is out of range for JosieIndexMap of length {len}"),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> Extend<(K, V)> for JosieIndexMap<K, V, S> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let iterator = iter.into_iter();
+        self.reserve(extend_reserve(self.is_empty(), iterator.size_hint().0));
+        for (key, value) in iterator {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<'a, K: Eq + Hash + Copy, V: Copy, S: BuildHasher> Extend<(&'a K, &'a V)>
+    for JosieIndexMap<K, V, S>
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (&'a K, &'a V)>,
+    {
+        self.extend(iter.into_iter().map(|(&key, &value)| (key, value)))
+    }
+}
+
+impl<K: Eq + Hash, V> FromIterator<(K, V)> for JosieIndexMap<K, V> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut temp = Self::new();
+        temp.extend(iter);
+        temp
+    }
+}
+
+impl<K: Eq + Hash, V, const N: usize> From<[(K, V); N]> for JosieIndexMap<K, V> {
+    fn from(array: [(K, V); N]) -> Self {
+        Self::from_iter(array)
+    }
+}
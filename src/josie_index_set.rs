@@ -0,0 +1,427 @@
+// ===============================
+//       JOSIE INDEX SET
+// -------------------------------
+// Insertion ordered hash set stored as a JosieIndexMap with () values
+// -------------------------------
+
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt,
+    hash::{BuildHasher, Hash, RandomState},
+    iter::FusedIterator,
+    ops::{Index, RangeBounds},
+};
+
+use crate::{
+    josie_index_map::{JosieIndexMap, JosieIndexMapDrain, JosieIndexMapIter, JosieIndexMapKeys},
+    josie_vec::josievec_error::JosieTryReserveError,
+};
+
+///Hash set that iterates in insertion order and can be indexed by position
+pub struct JosieIndexSet<T, S = RandomState> {
+    map: JosieIndexMap<T, (), S>,
+}
+
+//Constructors using the default hasher
+impl<T> JosieIndexSet<T> {
+    ///Creates an empty JosieIndexSet without allocating
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///Creates an empty JosieIndexSet with room for at least cap elements
+    pub fn with_capacity(cap: usize) -> Self {
+        Self::with_capacity_and_hasher(cap, RandomState::new())
+    }
+}
+
+impl<T, S> JosieIndexSet<T, S> {
+    ///Creates an empty JosieIndexSet that hashes elements with hasher, without allocating
+    #[inline(always)]
+    pub const fn with_hasher(hasher: S) -> Self {
+        Self {
+            map: JosieIndexMap::with_hasher(hasher),
+        }
+    }
+
+    ///Creates an empty JosieIndexSet with room for at least cap elements that hashes elements with hasher
+    pub fn with_capacity_and_hasher(cap: usize, hasher: S) -> Self {
+        Self {
+            map: JosieIndexMap::with_capacity_and_hasher(cap, hasher),
+        }
+    }
+
+    ///Returns the hasher builder of the set
+    #[inline(always)]
+    pub fn hasher(&self) -> &S {
+        self.map.hasher()
+    }
+
+    ///Number of elements in the set
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    ///Returns true if the set has no elements
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    ///Number of elements that fit before the set has to grow
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    ///Returns an iterator over the elements in order
+    pub fn iter(&self) -> JosieIndexSetIterRef<'_, T> {
+        JosieIndexSetIterRef {
+            iter: self.map.keys(),
+        }
+    }
+
+    ///Returns the element at position index
+    pub fn get_index(&self, index: usize) -> Option<&T> {
+        self.map.get_index(index).map(|(element, _)| element)
+    }
+
+    ///Returns the first element
+    pub fn first(&self) -> Option<&T> {
+        self.map.first().map(|(element, _)| element)
+    }
+
+    ///Returns the last element
+    pub fn last(&self) -> Option<&T> {
+        self.map.last().map(|(element, _)| element)
+    }
+
+    ///Removes the element at position index, moving every element after it down one. O(n)
+    pub fn shift_remove_index(&mut self, index: usize) -> Option<T> {
+        self.map
+            .shift_remove_index(index)
+            .map(|(element, _)| element)
+    }
+
+    ///Drops every element, keeping the allocations
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+}
+
+impl<T: Eq + Hash, S: BuildHasher> JosieIndexSet<T, S> {
+    ///Reserves room for at least additional more elements
+    #[inline(always)]
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional)
+    }
+
+    ///Reserves room for at least additional more elements, returning an error instead of panicking or
+    ///aborting if the set can not grow
+    #[inline(always)]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), JosieTryReserveError> {
+        self.map.try_reserve(additional)
+    }
+
+    ///Shrinks the elements and the index to fit the elements there are
+    #[inline(always)]
+    pub fn shrink_to_fit(&mut self) {
+        self.map.shrink_to_fit()
+    }
+
+    ///Returns true if the set holds value
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(value)
+    }
+
+    ///Returns the stored element equal to value
+    pub fn get<Q>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get_key_value(value).map(|(element, _)| element)
+    }
+
+    ///Returns the position of value
+    pub fn get_index_of<Q>(&self, value: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get_index_of(value)
+    }
+
+    ///Adds value to the end of the set, returning false if it was already there. An element that was
+    ///already there keeps its position
+    pub fn insert(&mut self, value: T) -> bool {
+        self.insert_full(value).1
+    }
+
+    ///Adds value to the end of the set, returning its position and whether it was new
+    pub fn insert_full(&mut self, value: T) -> (usize, bool) {
+        let (position, old) = self.map.insert_full(value, ());
+        (position, old.is_none())
+    }
+
+    ///Removes value by swapping the last element into its place. O(1) but changes the order
+    pub fn swap_remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.swap_remove(value).is_some()
+    }
+
+    ///Removes value, moving every element after it down one so the order is kept. O(n)
+    pub fn shift_remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.shift_remove(value).is_some()
+    }
+
+    ///Removes the element at position index by swapping the last element into its place. O(1)
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<T> {
+        self.map
+            .swap_remove_index(index)
+            .map(|(element, _)| element)
+    }
+
+    ///Removes and returns the last element
+    pub fn pop(&mut self) -> Option<T> {
+        self.map.pop().map(|(element, _)| element)
+    }
+
+    ///Removes the elements in range and returns them in order
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> JosieIndexSetDrain<'_, T, S> {
+        JosieIndexSetDrain {
+            iter: self.map.drain(range),
+        }
+    }
+
+    ///Keeps only the elements the predicate returns true for, in the order they were in
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.map.retain(|element, _| f(element))
+    }
+
+    ///Sorts the elements
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.map.sort_keys()
+    }
+
+    ///Sorts the elements with a comparison. The sort is stable
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.map.sort_by(|a, _, b, _| compare(a, b))
+    }
+
+    ///Returns true if every element of self is in other
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.len() <= other.len() && self.iter().all(|element| other.contains(element))
+    }
+
+    ///Returns true if every element of other is in self
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+}
+
+// ===============================
+// ITERATORS
+// -------------------------------
+
+///Iterator over references to the elements of a JosieIndexSet, in order
+#[derive(Clone)]
+pub struct JosieIndexSetIterRef<'a, T> {
+    iter: JosieIndexMapKeys<'a, T, ()>,
+}
+
+impl<'a, T> Iterator for JosieIndexSetIterRef<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for JosieIndexSetIterRef<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+///Owning iterator over the elements of a JosieIndexSet, in order
+pub struct JosieIndexSetIter<T> {
+    iter: JosieIndexMapIter<T, ()>,
+}
+
+impl<T> Iterator for JosieIndexSetIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.iter.next().map(|(element, _)| element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for JosieIndexSetIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back().map(|(element, _)| element)
+    }
+}
+
+///Draining iterator for JosieIndexSet, returned by JosieIndexSet::drain
+pub struct JosieIndexSetDrain<'a, T: Eq + Hash, S: BuildHasher = RandomState> {
+    iter: JosieIndexMapDrain<'a, T, (), S>,
+}
+
+impl<T: Eq + Hash, S: BuildHasher> Iterator for JosieIndexSetDrain<'_, T, S> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.iter.next().map(|(element, _)| element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T: Eq + Hash, S: BuildHasher> DoubleEndedIterator for JosieIndexSetDrain<'_, T, S> {
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back().map(|(element, _)| element)
+    }
+}
+
+impl<T> ExactSizeIterator for JosieIndexSetIterRef<'_, T> {}
+impl<T> ExactSizeIterator for JosieIndexSetIter<T> {}
+impl<T: Eq + Hash, S: BuildHasher> ExactSizeIterator for JosieIndexSetDrain<'_, T, S> {}
+
+impl<T> FusedIterator for JosieIndexSetIterRef<'_, T> {}
+impl<T> FusedIterator for JosieIndexSetIter<T> {}
+impl<T: Eq + Hash, S: BuildHasher> FusedIterator for JosieIndexSetDrain<'_, T, S> {}
+
+impl<T, S> IntoIterator for JosieIndexSet<T, S> {
+    type Item = T;
+    type IntoIter = JosieIndexSetIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        JosieIndexSetIter {
+            iter: self.map.into_iter(),
+        }
+    }
+}
+
+impl<'a, T, S> IntoIterator for &'a JosieIndexSet<T, S> {
+    type Item = &'a T;
+    type IntoIter = JosieIndexSetIterRef<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// ===============================
+// TRAITS
+// -------------------------------
+
+impl<T, S: Default> Default for JosieIndexSet<T, S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<T: Clone, S: Clone> Clone for JosieIndexSet<T, S> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug, S> fmt::Debug for JosieIndexSet<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+//equal if they hold the same elements, the order doesnt matter
+impl<T: Eq + Hash, S: BuildHasher> PartialEq for JosieIndexSet<T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.is_subset(other)
+    }
+}
+
+impl<T: Eq + Hash, S: BuildHasher> Eq for JosieIndexSet<T, S> {}
+
+impl<T, S> Index<usize> for JosieIndexSet<T, S> {
+    type Output = T;
+    fn index(&self, index: usize) -> &T {
+        match self.get_index(index) {
+            Some(element) => element,
+            None => panic!(
+                "Index {index} is out of range for JosieIndexSet of length {}",
+                self.len()
+            ),
+        }
+    }
+}
+
+impl<T: Eq + Hash, S: BuildHasher> Extend<T> for JosieIndexSet<T, S> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.map
+            .extend(iter.into_iter().map(|element| (element, ())))
+    }
+}
+
+impl<'a, T: Eq + Hash + Copy, S: BuildHasher> Extend<&'a T> for JosieIndexSet<T, S> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = &'a T>,
+    {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+impl<T: Eq + Hash> FromIterator<T> for JosieIndexSet<T> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut temp = Self::new();
+        temp.extend(iter);
+        temp
+    }
+}
+
+impl<T: Eq + Hash, const N: usize> From<[T; N]> for JosieIndexSet<T> {
+    fn from(array: [T; N]) -> Self {
+        Self::from_iter(array)
+    }
+}
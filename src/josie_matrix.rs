@@ -0,0 +1,770 @@
+// ===============================
+//       JOSIE MATRIX
+// -------------------------------
+// Dense 2D matrix stored row major in a single josievec, so element (r, c) lives at r * cols + c.
+// Rows are contiguous slices and columns are strided views over the same buffer. The josievec always
+// holds exactly rows * cols elements, everything that changes the shape keeps that true even if it
+// panics
+// -------------------------------
+
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    iter::{repeat_n, FusedIterator, StepBy},
+    mem::take,
+    ops::{Index, IndexMut},
+    ptr::copy,
+    slice,
+};
+
+use crate::josie_vec::JosieVec;
+
+//side of the square tiles transpose moves at a time, small enough that a tile of the source and of
+//the destination stay in cache together
+const TRANSPOSE_BLOCK: usize = 16;
+
+///Dense matrix with rows * cols elements stored row major in one josievec
+pub struct JosieMatrix<T> {
+    data: JosieVec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> JosieMatrix<T> {
+    ///Creates an empty 0x0 matrix without allocating
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::with_capacity(0, 0)
+    }
+
+    ///Creates a matrix with no rows and cols columns, with room for rows rows
+    pub fn with_capacity(rows: usize, cols: usize) -> Self {
+        Self {
+            data: JosieVec::with_capacity(element_count(rows, cols)),
+            rows: 0,
+            cols,
+        }
+    }
+
+    ///Creates a rows x cols matrix with f called on the position of every element, in row major order
+    pub fn from_fn<F>(rows: usize, cols: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let mut data = JosieVec::with_capacity(element_count(rows, cols));
+        for r in 0..rows {
+            for c in 0..cols {
+                data.push(f(r, c));
+            }
+        }
+        Self { data, rows, cols }
+    }
+
+    ///Creates a rows x cols matrix with every element a clone of value
+    pub fn from_elem(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(rows, cols, |_, _| value.clone())
+    }
+
+    ///Turns a josievec holding rows x cols elements in row major order into a matrix, reusing the
+    ///allocation. Panics if the josievec holds a different number of elements
+    pub fn from_josievec(rows: usize, cols: usize, josievec: JosieVec<T>) -> Self {
+        let len = josievec.len();
+        if len != element_count(rows, cols) {
+            panic!("Tried to make a {rows}x{cols} JosieMatrix out of {len} elements")
+        }
+        Self {
+            data: josievec,
+            rows,
+            cols,
+        }
+    }
+
+    ///Number of rows
+    #[inline(always)]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    ///Number of columns
+    #[inline(always)]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    ///Returns (rows, cols)
+    #[inline(always)]
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    ///Number of elements, rows * cols
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    ///Returns true if the matrix has no elements
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    ///Number of elements that fit before the josievec reallocates
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    ///Reserves room for at least additional more rows
+    pub fn reserve_rows(&mut self, additional: usize) {
+        self.data.reserve(element_count(additional, self.cols))
+    }
+
+    ///Returns the elements in row major order
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_slice()
+    }
+
+    ///Returns the elements mutably in row major order. The shape can not change through it
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.data.as_mut_slice()
+    }
+
+    ///Returns an iterator over every element in row major order
+    #[inline(always)]
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.data.as_slice().iter()
+    }
+
+    ///Returns an iterator over mutable references to every element in row major order
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.data.as_mut_slice().iter_mut()
+    }
+
+    ///Returns the element at row r and column c
+    pub fn get(&self, (r, c): (usize, usize)) -> Option<&T> {
+        if r < self.rows && c < self.cols {
+            Some(&self.data[r * self.cols + c])
+        } else {
+            None
+        }
+    }
+
+    ///Returns the element at row r and column c mutably
+    pub fn get_mut(&mut self, (r, c): (usize, usize)) -> Option<&mut T> {
+        if r < self.rows && c < self.cols {
+            Some(&mut self.data[r * self.cols + c])
+        } else {
+            None
+        }
+    }
+
+    ///Returns row r as a slice. Panics if r is out of bounds
+    pub fn row(&self, r: usize) -> &[T] {
+        self.check_row(r);
+        &self.data[r * self.cols..(r + 1) * self.cols]
+    }
+
+    ///Returns row r as a mutable slice. Panics if r is out of bounds
+    pub fn row_mut(&mut self, r: usize) -> &mut [T] {
+        self.check_row(r);
+        &mut self.data[r * self.cols..(r + 1) * self.cols]
+    }
+
+    ///Returns a view of column c. Panics if c is out of bounds
+    pub fn column(&self, c: usize) -> JosieMatrixColumn<'_, T> {
+        self.check_column(c);
+        JosieMatrixColumn {
+            data: column_slice(&self.data, c),
+            stride: self.cols,
+            len: self.rows,
+        }
+    }
+
+    ///Returns a mutable view of column c. Panics if c is out of bounds
+    pub fn column_mut(&mut self, c: usize) -> JosieMatrixColumnMut<'_, T> {
+        self.check_column(c);
+        let start = c.min(self.data.len());
+        JosieMatrixColumnMut {
+            data: &mut self.data[start..],
+            stride: self.cols,
+            len: self.rows,
+        }
+    }
+
+    ///Returns an iterator over the rows as slices
+    pub fn iter_rows(&self) -> JosieMatrixRows<'_, T> {
+        JosieMatrixRows {
+            data: self.data.as_slice(),
+            cols: self.cols,
+            remaining: self.rows,
+        }
+    }
+
+    ///Returns an iterator over the rows as mutable slices
+    pub fn iter_rows_mut(&mut self) -> JosieMatrixRowsMut<'_, T> {
+        JosieMatrixRowsMut {
+            data: self.data.as_mut_slice(),
+            cols: self.cols,
+            remaining: self.rows,
+        }
+    }
+
+    ///Returns an iterator over views of the columns
+    pub fn iter_columns(&self) -> JosieMatrixColumns<'_, T> {
+        JosieMatrixColumns {
+            data: self.data.as_slice(),
+            rows: self.rows,
+            cols: self.cols,
+            front: 0,
+            back: self.cols,
+        }
+    }
+
+    ///Adds row to the bottom of the matrix. A matrix with no rows takes its column count from the row.
+    ///Panics if the row has a different number of elements than the matrix has columns
+    pub fn push_row<I>(&mut self, row: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        //the row is collected first so the matrix is never left with half a row
+        let mut row = JosieVec::from_iter(row);
+        let len = row.len();
+        if self.rows == 0 {
+            self.cols = len;
+        } else if len != self.cols {
+            panic!(
+                "Tried to push a row of {len} elements onto a JosieMatrix with {} columns",
+                self.cols
+            )
+        }
+        self.data.append(&mut row);
+        self.rows += 1;
+    }
+
+    ///Removes the bottom row and returns it
+    pub fn pop_row(&mut self) -> Option<JosieVec<T>> {
+        if self.rows == 0 {
+            return None;
+        }
+        self.rows -= 1;
+        Some(self.data.split_off(self.rows * self.cols))
+    }
+
+    ///Inserts column at position c, moving every column from c on one to the right. A matrix with no
+    ///columns takes its row count from the column. Panics if c is greater than the number of columns or
+    ///the column has a different number of elements than the matrix has rows. O(rows * cols)
+    pub fn insert_column<I>(&mut self, c: usize, column: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        if c > self.cols {
+            panic!(
+                "Tried to insert column {c} into a JosieMatrix with {} columns",
+                self.cols
+            )
+        }
+        //the column is collected first so nothing is moved until it is known to fit
+        let mut column = JosieVec::from_iter(column);
+        let rows = column.len();
+        if self.cols != 0 && rows != self.rows {
+            panic!(
+                "Tried to insert a column of {rows} elements into a JosieMatrix with {} rows",
+                self.rows
+            )
+        }
+        let old_cols = self.cols;
+        let new_cols = old_cols + 1;
+        self.data.reserve(rows);
+        unsafe {
+            //nothing below can panic, the lengths are zeroed anyway so an element can never be dropped twice
+            let len = self.data.len();
+            self.data.set_len(0);
+            column.set_len(0);
+            let data = self.data.as_mut_ptr();
+            //goes from the bottom row up so every row moves to the right of where it was and is never
+            //written over before it has moved
+            for r in (0..rows).rev() {
+                let src = data.add(r * old_cols);
+                let dst = data.add(r * new_cols);
+                copy(src.add(c), dst.add(c + 1), old_cols - c);
+                copy(src, dst, c);
+                dst.add(c).write(column.as_ptr().add(r).read());
+            }
+            self.data.set_len(len + rows);
+        }
+        self.rows = rows;
+        self.cols = new_cols;
+    }
+
+    ///Changes the shape to rows x cols. Elements that are still inside the new shape keep their position,
+    ///the rest are dropped, and new positions are filled with clones of value
+    pub fn resize(&mut self, rows: usize, cols: usize, value: T)
+    where
+        T: Clone,
+    {
+        if cols == self.cols {
+            //rows are contiguous so only the end of the josievec changes
+            let len = element_count(rows, cols);
+            if len <= self.data.len() {
+                self.data.truncate(len);
+            } else {
+                //the new rows are cloned off to the side so a panicking clone leaves the matrix as it was
+                let mut added = JosieVec::with_capacity(len - self.data.len());
+                added.extend(repeat_n(value, len - self.data.len()));
+                self.data.append(&mut added);
+            }
+            self.rows = rows;
+            return;
+        }
+        //moves every element into a new josievec. if a clone panics the matrix is left empty, never the
+        //wrong shape
+        let mut old = take(&mut self.data).into_iter();
+        let old_cols = self.cols;
+        let old_rows = self.rows;
+        self.rows = 0;
+        self.cols = 0;
+        let mut data = JosieVec::with_capacity(element_count(rows, cols));
+        for r in 0..rows {
+            let mut kept = 0;
+            if r < old_rows {
+                kept = old_cols.min(cols);
+                data.extend(old.by_ref().take(kept));
+                //drops the part of the old row that falls outside the new shape
+                old.by_ref().take(old_cols - kept).for_each(drop);
+            }
+            for _ in kept..cols {
+                data.push(value.clone());
+            }
+        }
+        self.data = data;
+        self.rows = rows;
+        self.cols = cols;
+    }
+
+    ///Returns the transposed matrix, with rows and columns swapped. Moves the elements in square tiles
+    ///so both the reads and the writes stay in cache
+    pub fn transpose(mut self) -> Self {
+        let (rows, cols) = (self.rows, self.cols);
+        let len = self.data.len();
+        let mut out: JosieVec<T> = JosieVec::with_capacity(len);
+        unsafe {
+            //the elements are moved bit for bit, so the old josievec forgets them before any are read
+            self.data.set_len(0);
+            let src = self.data.as_ptr();
+            let dst = out.as_mut_ptr();
+            for row_block in (0..rows).step_by(TRANSPOSE_BLOCK) {
+                for col_block in (0..cols).step_by(TRANSPOSE_BLOCK) {
+                    for r in row_block..(row_block + TRANSPOSE_BLOCK).min(rows) {
+                        for c in col_block..(col_block + TRANSPOSE_BLOCK).min(cols) {
+                            dst.add(c * rows + r).write(src.add(r * cols + c).read());
+                        }
+                    }
+                }
+            }
+            out.set_len(len);
+        }
+        Self {
+            data: out,
+            rows: cols,
+            cols: rows,
+        }
+    }
+
+    ///Returns the josievec the elements are stored in, in row major order
+    #[inline(always)]
+    pub fn into_josievec(self) -> JosieVec<T> {
+        self.data
+    }
+
+    fn check_row(&self, r: usize) {
+        if r >= self.rows {
+            panic!(
+                "Row {r} is out of range for JosieMatrix with {} rows",
+                self.rows
+            )
+        }
+    }
+
+    fn check_column(&self, c: usize) {
+        if c >= self.cols {
+            panic!(
+                "Column {c} is out of range for JosieMatrix with {} columns",
+                self.cols
+            )
+        }
+    }
+}
+
+//rows * cols, panicking instead of wrapping
+fn element_count(rows: usize, cols: usize) -> usize {
+    rows.checked_mul(cols).unwrap_or_else(|| {
+        panic!("A {rows}x{cols} JosieMatrix has more elements than fit in usize")
+    })
+}
+
+//data from the first element of column c on. a matrix with no rows has no elements to start from
+fn column_slice<T>(data: &[T], c: usize) -> &[T] {
+    &data[c.min(data.len())..]
+}
+
+// ===============================
+// COLUMN VIEWS
+// -------------------------------
+
+///View of one column of a JosieMatrix. Element i of the column is element i * stride of data
+#[derive(Clone, Copy)]
+pub struct JosieMatrixColumn<'a, T> {
+    data: &'a [T],
+    stride: usize,
+    len: usize,
+}
+
+impl<'a, T> JosieMatrixColumn<'a, T> {
+    ///Number of elements in the column, the number of rows of the matrix
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    ///Returns true if the column has no elements
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    ///Returns the element in row i
+    pub fn get(&self, i: usize) -> Option<&'a T> {
+        if i < self.len {
+            Some(&self.data[i * self.stride])
+        } else {
+            None
+        }
+    }
+
+    ///Returns an iterator over the column from the top row down
+    pub fn iter(&self) -> StepBy<slice::Iter<'a, T>> {
+        self.data.iter().step_by(self.stride)
+    }
+}
+
+impl<T> Index<usize> for JosieMatrixColumn<'_, T> {
+    type Output = T;
+    fn index(&self, i: usize) -> &T {
+        self.get(i).unwrap_or_else(|| {
+            panic!(
+                "Index {i} is out of range for column of length {}",
+                self.len
+            )
+        })
+    }
+}
+
+impl<'a, T> IntoIterator for JosieMatrixColumn<'a, T> {
+    type Item = &'a T;
+    type IntoIter = StepBy<slice::Iter<'a, T>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for JosieMatrixColumn<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+///Mutable view of one column of a JosieMatrix. Element i of the column is element i * stride of data
+pub struct JosieMatrixColumnMut<'a, T> {
+    data: &'a mut [T],
+    stride: usize,
+    len: usize,
+}
+
+impl<T> JosieMatrixColumnMut<'_, T> {
+    ///Number of elements in the column, the number of rows of the matrix
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    ///Returns true if the column has no elements
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    ///Returns the element in row i
+    pub fn get(&self, i: usize) -> Option<&T> {
+        if i < self.len {
+            Some(&self.data[i * self.stride])
+        } else {
+            None
+        }
+    }
+
+    ///Returns the element in row i mutably
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        if i < self.len {
+            Some(&mut self.data[i * self.stride])
+        } else {
+            None
+        }
+    }
+
+    ///Returns an iterator over the column from the top row down
+    pub fn iter(&self) -> StepBy<slice::Iter<'_, T>> {
+        self.data.iter().step_by(self.stride)
+    }
+
+    ///Returns an iterator over mutable references to the column from the top row down
+    pub fn iter_mut(&mut self) -> StepBy<slice::IterMut<'_, T>> {
+        self.data.iter_mut().step_by(self.stride)
+    }
+}
+
+impl<T> Index<usize> for JosieMatrixColumnMut<'_, T> {
+    type Output = T;
+    fn index(&self, i: usize) -> &T {
+        self.get(i).unwrap_or_else(|| {
+            panic!(
+                "Index {i} is out of range for column of length {}",
+                self.len
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<usize> for JosieMatrixColumnMut<'_, T> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        let len = self.len;
+        self.get_mut(i)
+            .unwrap_or_else(|| panic!("Index {i} is out of range for column of length {len}"))
+    }
+}
+
+impl<'a, T> IntoIterator for JosieMatrixColumnMut<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = StepBy<slice::IterMut<'a, T>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut().step_by(self.stride)
+    }
+}
+
+// ===============================
+// ITERATORS
+// -------------------------------
+
+///Iterator over the rows of a JosieMatrix as slices
+#[derive(Clone)]
+pub struct JosieMatrixRows<'a, T> {
+    data: &'a [T],
+    cols: usize,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for JosieMatrixRows<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let (row, rest) = self.data.split_at(self.cols);
+        self.data = rest;
+        Some(row)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for JosieMatrixRows<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let (rest, row) = self.data.split_at(self.data.len() - self.cols);
+        self.data = rest;
+        Some(row)
+    }
+}
+
+impl<T> ExactSizeIterator for JosieMatrixRows<'_, T> {}
+impl<T> FusedIterator for JosieMatrixRows<'_, T> {}
+
+///Iterator over the rows of a JosieMatrix as mutable slices
+pub struct JosieMatrixRowsMut<'a, T> {
+    data: &'a mut [T],
+    cols: usize,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for JosieMatrixRowsMut<'a, T> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<&'a mut [T]> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let (row, rest) = take(&mut self.data).split_at_mut(self.cols);
+        self.data = rest;
+        Some(row)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for JosieMatrixRowsMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let data = take(&mut self.data);
+        let split = data.len() - self.cols;
+        let (rest, row) = data.split_at_mut(split);
+        self.data = rest;
+        Some(row)
+    }
+}
+
+impl<T> ExactSizeIterator for JosieMatrixRowsMut<'_, T> {}
+impl<T> FusedIterator for JosieMatrixRowsMut<'_, T> {}
+
+///Iterator over views of the columns of a JosieMatrix
+#[derive(Clone)]
+pub struct JosieMatrixColumns<'a, T> {
+    data: &'a [T],
+    rows: usize,
+    cols: usize,
+    //next column from the front and one past the next column from the back
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> JosieMatrixColumns<'a, T> {
+    fn column(&self, c: usize) -> JosieMatrixColumn<'a, T> {
+        JosieMatrixColumn {
+            data: column_slice(self.data, c),
+            stride: self.cols,
+            len: self.rows,
+        }
+    }
+}
+
+impl<'a, T> Iterator for JosieMatrixColumns<'a, T> {
+    type Item = JosieMatrixColumn<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.column(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.back - self.front;
+        (size, Some(size))
+    }
+}
+
+impl<T> DoubleEndedIterator for JosieMatrixColumns<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.column(self.back))
+    }
+}
+
+impl<T> ExactSizeIterator for JosieMatrixColumns<'_, T> {}
+impl<T> FusedIterator for JosieMatrixColumns<'_, T> {}
+
+// ===============================
+// TRAITS
+// -------------------------------
+
+impl<T> Default for JosieMatrix<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for JosieMatrix<T> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+//prints one row per entry
+impl<T: fmt::Debug> fmt::Debug for JosieMatrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter_rows()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for JosieMatrix<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.shape() == other.shape() && self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq> Eq for JosieMatrix<T> {}
+
+impl<T: Hash> Hash for JosieMatrix<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.shape().hash(state);
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T> Index<(usize, usize)> for JosieMatrix<T> {
+    type Output = T;
+    fn index(&self, (r, c): (usize, usize)) -> &T {
+        let (rows, cols) = self.shape();
+        self.get((r, c)).unwrap_or_else(|| {
+            panic!("Index ({r}, {c}) is out of range for {rows}x{cols} JosieMatrix")
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for JosieMatrix<T> {
+    fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut T {
+        let (rows, cols) = self.shape();
+        self.get_mut((r, c)).unwrap_or_else(|| {
+            panic!("Index ({r}, {c}) is out of range for {rows}x{cols} JosieMatrix")
+        })
+    }
+}
+
+//each inner array is one row
+impl<T, const ROWS: usize, const COLS: usize> From<[[T; COLS]; ROWS]> for JosieMatrix<T> {
+    fn from(array: [[T; COLS]; ROWS]) -> Self {
+        Self::from_josievec(ROWS, COLS, JosieVec::from_iter(array.into_iter().flatten()))
+    }
+}
+
+impl<T> From<JosieMatrix<T>> for JosieVec<T> {
+    fn from(matrix: JosieMatrix<T>) -> Self {
+        matrix.data
+    }
+}
@@ -0,0 +1,478 @@
+// ===============================
+//       JOSIE SLAB
+// -------------------------------
+// Generational arena on a josievec of slots. A vacant slot stores the index of the next vacant slot,
+// so the free list lives inside the josievec itself and inserting reuses the most recently freed
+// slot in O(1). Every slot has a generation that goes up when its entry is removed, and a key only
+// matches while the generation it was handed out with is still the generation of the slot, so a
+// key to a removed entry never reaches whatever is stored there next
+// -------------------------------
+
+use std::{
+    fmt,
+    iter::{Enumerate, FusedIterator},
+    mem::replace,
+    ops::{Index, IndexMut},
+    slice,
+};
+
+use crate::josie_vec::{
+    josievec_error::JosieTryReserveError, josievec_iter::JosieVecIter, JosieVec,
+};
+
+//next_free of the last vacant slot in the free list
+const FREE_LIST_END: usize = usize::MAX;
+
+///Handle to an entry in a JosieSlab. Stays the same while the entry lives and stops matching once
+///the entry is removed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct JosieSlabKey {
+    index: usize,
+    generation: u32,
+}
+
+impl JosieSlabKey {
+    ///Slot the entry is stored in
+    #[inline(always)]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    ///Generation of the slot when the key was handed out
+    #[inline(always)]
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+#[derive(Clone)]
+enum JosieSlabSlot<T> {
+    Occupied { generation: u32, value: T },
+    //generation is the one the next entry in this slot gets
+    Vacant { generation: u32, next_free: usize },
+}
+
+///Arena that hands out keys which stay valid until their entry is removed
+pub struct JosieSlab<T> {
+    slots: JosieVec<JosieSlabSlot<T>>,
+    //first vacant slot, or FREE_LIST_END if every slot is occupied
+    free_head: usize,
+    len: usize,
+    //generation a brand new slot starts at. compact drops slots off the end, so this is kept above
+    //every generation those slots had and a stale key can never match a slot that grew back
+    fresh_generation: u32,
+}
+
+impl<T> JosieSlab<T> {
+    ///Creates an empty JosieSlab without allocating
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    ///Creates an empty JosieSlab with room for cap entries
+    pub fn with_capacity(cap: usize) -> Self {
+        Self {
+            slots: JosieVec::with_capacity(cap),
+            free_head: FREE_LIST_END,
+            len: 0,
+            fresh_generation: 0,
+        }
+    }
+
+    ///Number of live entries
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    ///Returns true if there are no live entries
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    ///Number of entries that fit before the slab has to grow, counting vacant slots
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+
+    ///Reserves room for at least additional more entries. Vacant slots count towards it and the rest
+    ///is grown by the growth policy of the josievec
+    pub fn reserve(&mut self, additional: usize) {
+        let vacant = self.slots.len() - self.len;
+        self.slots.reserve(additional.saturating_sub(vacant))
+    }
+
+    ///Fallible version of reserve, returning an error instead of panicking or aborting
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), JosieTryReserveError> {
+        let vacant = self.slots.len() - self.len;
+        self.slots.try_reserve(additional.saturating_sub(vacant))
+    }
+
+    ///Stores value and returns its key
+    pub fn insert(&mut self, value: T) -> JosieSlabKey {
+        self.insert_with_key(|_| value)
+    }
+
+    ///Stores the value made by f, which is handed the key the value will have. Useful for entries that
+    ///need to know their own key
+    pub fn insert_with_key<F>(&mut self, f: F) -> JosieSlabKey
+    where
+        F: FnOnce(JosieSlabKey) -> T,
+    {
+        let index = match self.free_head {
+            FREE_LIST_END => self.slots.len(),
+            free => free,
+        };
+        let generation = match self.slots.get(index) {
+            Some(JosieSlabSlot::Vacant { generation, .. }) => *generation,
+            _ => self.fresh_generation,
+        };
+        let key = JosieSlabKey { index, generation };
+        //nothing has changed yet if f panics
+        let value = f(key);
+        let occupied = JosieSlabSlot::Occupied { generation, value };
+        if index == self.slots.len() {
+            self.slots.push(occupied);
+        } else if let JosieSlabSlot::Vacant { next_free, .. } =
+            replace(&mut self.slots[index], occupied)
+        {
+            self.free_head = next_free;
+        }
+        self.len += 1;
+        key
+    }
+
+    ///Returns a reference to the entry for key, or None if it was removed
+    pub fn get(&self, key: JosieSlabKey) -> Option<&T> {
+        match self.slots.get(key.index)? {
+            JosieSlabSlot::Occupied { generation, value } if *generation == key.generation => {
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+
+    ///Returns a mutable reference to the entry for key, or None if it was removed
+    pub fn get_mut(&mut self, key: JosieSlabKey) -> Option<&mut T> {
+        match self.slots.get_mut(key.index)? {
+            JosieSlabSlot::Occupied { generation, value } if *generation == key.generation => {
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+
+    ///Returns true if key still points at a live entry
+    #[inline(always)]
+    pub fn contains(&self, key: JosieSlabKey) -> bool {
+        self.get(key).is_some()
+    }
+
+    ///Removes the entry for key and returns it, or None if it was already removed
+    pub fn remove(&mut self, key: JosieSlabKey) -> Option<T> {
+        self.get(key)?;
+        Some(self.remove_at(key.index))
+    }
+
+    //frees the occupied slot at index and puts it at the front of the free list
+    fn remove_at(&mut self, index: usize) -> T {
+        let generation = match &self.slots[index] {
+            JosieSlabSlot::Occupied { generation, .. } => *generation,
+            JosieSlabSlot::Vacant { .. } => unreachable!("only occupied slots are removed"),
+        };
+        let vacant = JosieSlabSlot::Vacant {
+            //every key handed out for the old entry stops matching
+            generation: generation.wrapping_add(1),
+            next_free: self.free_head,
+        };
+        self.free_head = index;
+        self.len -= 1;
+        match replace(&mut self.slots[index], vacant) {
+            JosieSlabSlot::Occupied { value, .. } => value,
+            JosieSlabSlot::Vacant { .. } => unreachable!("only occupied slots are removed"),
+        }
+    }
+
+    ///Keeps only the entries the predicate returns true for
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(JosieSlabKey, &mut T) -> bool,
+    {
+        for index in 0..self.slots.len() {
+            let keep = match &mut self.slots[index] {
+                JosieSlabSlot::Occupied { generation, value } => f(
+                    JosieSlabKey {
+                        index,
+                        generation: *generation,
+                    },
+                    value,
+                ),
+                JosieSlabSlot::Vacant { .. } => true,
+            };
+            if !keep {
+                drop(self.remove_at(index));
+            }
+        }
+    }
+
+    ///Removes every entry, keeping the slots so every key handed out so far stops matching
+    pub fn clear(&mut self) {
+        self.retain(|_, _| false)
+    }
+
+    ///Moves the entries at the back into the vacant slots at the front, then drops the vacant slots
+    ///off the end and shrinks the josievec. remap is called with each moved entry, its old key and its
+    ///new key so anything holding the old key can be pointed at the new one
+    pub fn compact<F>(&mut self, mut remap: F)
+    where
+        F: FnMut(&mut T, JosieSlabKey, JosieSlabKey),
+    {
+        //every vacant slot is either filled or dropped, so the free list is rebuilt from nothing. if
+        //remap panics the vacant slots that are left are never handed out by insert, only filled by a
+        //later compact
+        self.free_head = FREE_LIST_END;
+        let mut hole = 0;
+        let mut end = self.slots.len();
+        loop {
+            while hole < end && matches!(self.slots[hole], JosieSlabSlot::Occupied { .. }) {
+                hole += 1;
+            }
+            while end > hole && matches!(self.slots[end - 1], JosieSlabSlot::Vacant { .. }) {
+                end -= 1;
+            }
+            if hole >= end {
+                break;
+            }
+            //slots[hole] is vacant and slots[end - 1] is occupied, so hole is before end - 1
+            end -= 1;
+            let new_generation = match self.slots[hole] {
+                JosieSlabSlot::Vacant { generation, .. } => generation,
+                JosieSlabSlot::Occupied { .. } => unreachable!("hole is always vacant"),
+            };
+            let old_generation = match self.slots[end] {
+                JosieSlabSlot::Occupied { generation, .. } => generation,
+                JosieSlabSlot::Vacant { .. } => unreachable!("end - 1 is always occupied"),
+            };
+            //bumps the generation like remove_at does, so if remap panics and this slot is kept and later
+            //filled the old key still doesnt match
+            let moved = replace(
+                &mut self.slots[end],
+                JosieSlabSlot::Vacant {
+                    generation: old_generation.wrapping_add(1),
+                    next_free: FREE_LIST_END,
+                },
+            );
+            let JosieSlabSlot::Occupied { generation, value } = moved else {
+                unreachable!("end - 1 is always occupied")
+            };
+            self.fresh_generation = self.fresh_generation.max(generation.wrapping_add(1));
+            self.slots[hole] = JosieSlabSlot::Occupied {
+                generation: new_generation,
+                value,
+            };
+            if let JosieSlabSlot::Occupied { value, .. } = &mut self.slots[hole] {
+                remap(
+                    value,
+                    JosieSlabKey {
+                        index: end,
+                        generation,
+                    },
+                    JosieSlabKey {
+                        index: hole,
+                        generation: new_generation,
+                    },
+                );
+            }
+        }
+        //slots past end are all vacant, their generations are kept in fresh_generation before they go
+        for slot in &self.slots[end..] {
+            if let JosieSlabSlot::Vacant { generation, .. } = slot {
+                self.fresh_generation = self.fresh_generation.max(*generation);
+            }
+        }
+        self.slots.truncate(end);
+        self.slots.shrink_to_fit();
+    }
+
+    ///Returns an iterator over the live entries and their keys, in slot order
+    pub fn iter(&self) -> JosieSlabIterRef<'_, T> {
+        JosieSlabIterRef {
+            iter: self.slots.as_slice().iter().enumerate(),
+            remaining: self.len,
+        }
+    }
+
+    ///Returns an iterator over the live entries and their keys with mutable entries, in slot order
+    pub fn iter_mut(&mut self) -> JosieSlabIterMut<'_, T> {
+        JosieSlabIterMut {
+            remaining: self.len,
+            iter: self.slots.as_mut_slice().iter_mut().enumerate(),
+        }
+    }
+}
+
+// ===============================
+// ITERATORS
+// -------------------------------
+
+//implements the iterator traits for an iterator that skips the vacant slots of an enumerated slot iterator
+macro_rules! live_slot_iterator {
+    ($name:ident<$($lifetime:lifetime,)? T>, $item:ty, $slot:ty, $generation:expr) => {
+        impl<$($lifetime,)? T> $name<$($lifetime,)? T> {
+            fn map_slot((index, slot): (usize, $slot)) -> Option<$item> {
+                match slot {
+                    JosieSlabSlot::Occupied { generation, value } => {
+                        let key = JosieSlabKey { index, generation: ($generation)(generation) };
+                        Some((key, value))
+                    }
+                    JosieSlabSlot::Vacant { .. } => None,
+                }
+            }
+        }
+
+        impl<$($lifetime,)? T> Iterator for $name<$($lifetime,)? T> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let entry = self.iter.find_map(Self::map_slot)?;
+                self.remaining -= 1;
+                Some(entry)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.remaining, Some(self.remaining))
+            }
+        }
+
+        impl<$($lifetime,)? T> DoubleEndedIterator for $name<$($lifetime,)? T> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let entry = self.iter.by_ref().rev().find_map(Self::map_slot)?;
+                self.remaining -= 1;
+                Some(entry)
+            }
+        }
+
+        impl<$($lifetime,)? T> ExactSizeIterator for $name<$($lifetime,)? T> {}
+        impl<$($lifetime,)? T> FusedIterator for $name<$($lifetime,)? T> {}
+    };
+}
+
+///Iterator over the live entries of a JosieSlab and their keys
+pub struct JosieSlabIterRef<'a, T> {
+    iter: Enumerate<slice::Iter<'a, JosieSlabSlot<T>>>,
+    remaining: usize,
+}
+
+live_slot_iterator!(
+    JosieSlabIterRef<'a, T>,
+    (JosieSlabKey, &'a T),
+    &'a JosieSlabSlot<T>,
+    |generation: &u32| *generation
+);
+
+impl<T> Clone for JosieSlabIterRef<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            remaining: self.remaining,
+        }
+    }
+}
+
+///Iterator over the live entries of a JosieSlab and their keys with mutable entries
+pub struct JosieSlabIterMut<'a, T> {
+    iter: Enumerate<slice::IterMut<'a, JosieSlabSlot<T>>>,
+    remaining: usize,
+}
+
+live_slot_iterator!(
+    JosieSlabIterMut<'a, T>,
+    (JosieSlabKey, &'a mut T),
+    &'a mut JosieSlabSlot<T>,
+    |generation: &mut u32| *generation
+);
+
+///Owning iterator over the live entries of a JosieSlab and their keys
+pub struct JosieSlabIter<T> {
+    iter: Enumerate<JosieVecIter<JosieSlabSlot<T>>>,
+    remaining: usize,
+}
+
+live_slot_iterator!(
+    JosieSlabIter<T>,
+    (JosieSlabKey, T),
+    JosieSlabSlot<T>,
+    |generation: u32| generation
+);
+
+impl<T> IntoIterator for JosieSlab<T> {
+    type Item = (JosieSlabKey, T);
+    type IntoIter = JosieSlabIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        JosieSlabIter {
+            remaining: self.len,
+            iter: self.slots.into_iter().enumerate(),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a JosieSlab<T> {
+    type Item = (JosieSlabKey, &'a T);
+    type IntoIter = JosieSlabIterRef<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut JosieSlab<T> {
+    type Item = (JosieSlabKey, &'a mut T);
+    type IntoIter = JosieSlabIterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+// ===============================
+// TRAITS
+// -------------------------------
+
+impl<T> Default for JosieSlab<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for JosieSlab<T> {
+    fn clone(&self) -> Self {
+        Self {
+            slots: self.slots.clone(),
+            free_head: self.free_head,
+            len: self.len,
+            fresh_generation: self.fresh_generation,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for JosieSlab<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Index<JosieSlabKey> for JosieSlab<T> {
+    type Output = T;
+    fn index(&self, key: JosieSlabKey) -> &T {
+        self.get(key)
+            .expect("Key points at a removed entry of the JosieSlab")
+    }
+}
+
+impl<T> IndexMut<JosieSlabKey> for JosieSlab<T> {
+    fn index_mut(&mut self, key: JosieSlabKey) -> &mut T {
+        self.get_mut(key)
+            .expect("Key points at a removed entry of the JosieSlab")
+    }
+}
@@ -0,0 +1,161 @@
+// ===============================
+//       SLICE ITER
+// -------------------------------
+// Owning iteration and draining over a run of initialized elements, shared by the containers that
+// keep their elements in one contiguous slice (ArrayJosieVec, SmallJosieVec and a contiguous
+// JosieDeque). Positions are kept as indices rather than pointers because the inline buffers move
+// whenever the iterator that owns them does, so the caller passes in the buffer pointer on every call
+// -------------------------------
+
+use std::{
+    marker::PhantomData,
+    ops::Range,
+    ptr::{copy, drop_in_place, slice_from_raw_parts_mut},
+    slice::from_raw_parts,
+};
+
+///The elements of a buffer between start and end that havent been yielded yet
+pub(crate) struct SliceCursor {
+    start: usize,
+    end: usize,
+}
+
+impl SliceCursor {
+    #[inline(always)]
+    pub(crate) const fn new(range: Range<usize>) -> Self {
+        Self {
+            start: range.start,
+            end: range.end,
+        }
+    }
+
+    ///Number of elements left
+    #[inline(always)]
+    pub(crate) const fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    ///Returns the elements left as a slice. ptr has to point at the start of the buffer
+    #[inline(always)]
+    pub(crate) unsafe fn as_slice<'a, T>(&self, ptr: *const T) -> &'a [T] {
+        unsafe { from_raw_parts(ptr.add(self.start), self.len()) }
+    }
+
+    ///Reads the front element out of the buffer
+    #[inline(always)]
+    pub(crate) unsafe fn next<T>(&mut self, ptr: *const T) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+        self.start += 1;
+        Some(unsafe { ptr.add(self.start - 1).read() })
+    }
+
+    ///Reads the back element out of the buffer
+    #[inline(always)]
+    pub(crate) unsafe fn next_back<T>(&mut self, ptr: *const T) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(unsafe { ptr.add(self.end).read() })
+    }
+
+    ///Drops every element left. They are all marked as yielded first so nothing is dropped twice if a
+    ///drop panics
+    pub(crate) unsafe fn drop_remaining<T>(&mut self, ptr: *mut T) {
+        let (start, count) = (self.start, self.len());
+        self.start = self.end;
+        unsafe { drop_in_place(slice_from_raw_parts_mut(ptr.add(start), count)) }
+    }
+}
+
+///Container a SliceDrain can take elements out of. The elements have to be contiguous from the start
+///of the buffer for as long as the drain is alive
+pub(crate) trait SliceDrainSource<T> {
+    fn drain_ptr(&self) -> *const T;
+    fn drain_mut_ptr(&mut self) -> *mut T;
+    fn drain_len(&self) -> usize;
+    unsafe fn drain_set_len(&mut self, len: usize);
+}
+
+///Draining iterator over a range of a SliceDrainSource. The public drain types wrap this
+pub(crate) struct SliceDrain<'a, T, S: SliceDrainSource<T>> {
+    source: &'a mut S,
+    cursor: SliceCursor,
+    //where the elements after the range start and how many there are
+    tail_start: usize,
+    tail_len: usize,
+    marker: PhantomData<T>,
+}
+
+impl<'a, T, S: SliceDrainSource<T>> SliceDrain<'a, T, S> {
+    ///range has to be in bounds of the source
+    pub(crate) fn new(source: &'a mut S, range: Range<usize>) -> Self {
+        let len = source.drain_len();
+        //cuts the length down to the start of the range so leaking the drain cant expose drained elements
+        unsafe { source.drain_set_len(range.start) };
+        Self {
+            source,
+            tail_start: range.end,
+            tail_len: len - range.end,
+            cursor: SliceCursor::new(range),
+            marker: PhantomData,
+        }
+    }
+
+    ///Returns the elements that havent been yielded yet as a slice
+    pub(crate) fn as_slice(&self) -> &[T] {
+        unsafe { self.cursor.as_slice(self.source.drain_ptr()) }
+    }
+}
+
+impl<T, S: SliceDrainSource<T>> Iterator for SliceDrain<'_, T, S> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        unsafe { self.cursor.next(self.source.drain_ptr()) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.cursor.len();
+        (size, Some(size))
+    }
+}
+
+impl<T, S: SliceDrainSource<T>> DoubleEndedIterator for SliceDrain<'_, T, S> {
+    fn next_back(&mut self) -> Option<T> {
+        unsafe { self.cursor.next_back(self.source.drain_ptr()) }
+    }
+}
+
+//raii guard that moves the tail back after the unyielded elements are dropped, even if one of those drops panics
+struct SliceDrainTailGuard<'r, 'a, T, S: SliceDrainSource<T>> {
+    drain: &'r mut SliceDrain<'a, T, S>,
+}
+
+impl<T, S: SliceDrainSource<T>> Drop for SliceDrainTailGuard<'_, '_, T, S> {
+    fn drop(&mut self) {
+        let source = &mut *self.drain.source;
+        let start = source.drain_len();
+        unsafe {
+            let ptr = source.drain_mut_ptr();
+            copy(
+                ptr.add(self.drain.tail_start),
+                ptr.add(start),
+                self.drain.tail_len,
+            );
+            source.drain_set_len(start + self.drain.tail_len);
+        }
+    }
+}
+
+impl<T, S: SliceDrainSource<T>> Drop for SliceDrain<'_, T, S> {
+    fn drop(&mut self) {
+        let guard = SliceDrainTailGuard { drain: self };
+        unsafe {
+            let ptr = guard.drain.source.drain_mut_ptr();
+            guard.drain.cursor.drop_remaining(ptr);
+        }
+    }
+}
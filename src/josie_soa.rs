@@ -0,0 +1,452 @@
+// ===============================
+//       JOSIE SOA
+// -------------------------------
+// Struct of arrays containers made by the josie_soa! macro. The container keeps one josievec per field
+// of the struct instead of one josievec of structs, so a loop over a single field walks contiguous
+// memory. Every column always has the same length and row i is element i of every column. Macros by
+// example can not make up new names, so the invocation names the container and the two reference
+// structs it hands out for a row
+// -------------------------------
+
+use std::iter::FusedIterator;
+
+use crate::josie_vec::JosieVec;
+
+///Creates a struct and a struct of arrays container for it. The struct is written out as usual and is
+///followed by the names of the container, of the struct of references to one row and of the struct of
+///mutable references to one row. Attributes on each of them are kept, so they can derive whatever
+///their fields allow. Each field also gets a method returning its column as a slice, so a field can
+///not share its name with a method of the container.
+/// ```
+/// use josie_collections::josie_soa;
+/// josie_soa! {
+///     #[derive(Debug, PartialEq)]
+///     pub struct Particle {
+///         pub mass: f32,
+///         pub alive: bool,
+///     }
+///     #[derive(Debug)]
+///     pub struct ParticleSoA;
+///     pub struct ParticleRef;
+///     pub struct ParticleRefMut;
+/// }
+/// let mut particles = ParticleSoA::new();
+/// particles.push(Particle { mass: 2.0, alive: true });
+/// assert_eq!(particles.mass(), &[2.0]);
+/// ```
+#[macro_export]
+macro_rules! josie_soa {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $($(#[$field_attr:meta])* $field_vis:vis $field:ident: $field_ty:ty),+ $(,)?
+        }
+        $(#[$soa_attr:meta])*
+        $soa_vis:vis struct $soa:ident;
+        $(#[$ref_attr:meta])*
+        $ref_vis:vis struct $ref_name:ident;
+        $(#[$mut_attr:meta])*
+        $mut_vis:vis struct $ref_mut:ident;
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $($(#[$field_attr])* $field_vis $field: $field_ty),+
+        }
+
+        #[doc = concat!("Struct of arrays holding one josievec per field of ", stringify!($name))]
+        $(#[$soa_attr])*
+        $soa_vis struct $soa {
+            $($field: $crate::josie_vec::JosieVec<$field_ty>),+
+        }
+
+        #[doc = concat!("References to the fields of one row of ", stringify!($soa))]
+        $(#[$ref_attr])*
+        $ref_vis struct $ref_name<'a> {
+            $($field_vis $field: &'a $field_ty),+
+        }
+
+        #[doc = concat!("Mutable references to the fields of one row of ", stringify!($soa))]
+        $(#[$mut_attr])*
+        $mut_vis struct $ref_mut<'a> {
+            $($field_vis $field: &'a mut $field_ty),+
+        }
+
+        impl $soa {
+            ///Creates an empty container without allocating
+            pub fn new() -> Self {
+                Self {
+                    $($field: $crate::josie_vec::JosieVec::new()),+
+                }
+            }
+
+            ///Creates an empty container with room for cap rows in every column
+            pub fn with_capacity(cap: usize) -> Self {
+                Self {
+                    $($field: $crate::josie_vec::JosieVec::with_capacity(cap)),+
+                }
+            }
+
+            ///Number of rows
+            pub fn len(&self) -> usize {
+                //every column has the same length
+                0 $(.max(self.$field.len()))+
+            }
+
+            ///Returns true if there are no rows
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            ///Number of rows that fit before any column reallocates
+            pub fn capacity(&self) -> usize {
+                usize::MAX $(.min(self.$field.capacity()))+
+            }
+
+            ///Reserves room for at least additional more rows in every column
+            pub fn reserve(&mut self, additional: usize) {
+                $(self.$field.reserve(additional);)+
+            }
+
+            ///Splits value into its fields and pushes each onto its column
+            pub fn push(&mut self, value: $name) {
+                //every column has room before any is pushed to, so a failed allocation cant leave the
+                //columns with different lengths
+                self.reserve(1);
+                let $name { $($field),+ } = value;
+                $(self.$field.push($field);)+
+            }
+
+            ///Removes the last row and puts its fields back together
+            pub fn pop(&mut self) -> Option<$name> {
+                if self.is_empty() {
+                    return None;
+                }
+                Some($name {
+                    $($field: self.$field.pop()?),+
+                })
+            }
+
+            ///Returns references to the fields of row index
+            pub fn get(&self, index: usize) -> Option<$ref_name<'_>> {
+                if index >= self.len() {
+                    return None;
+                }
+                Some($ref_name {
+                    $($field: &self.$field[index]),+
+                })
+            }
+
+            ///Returns mutable references to the fields of row index
+            pub fn get_mut(&mut self, index: usize) -> Option<$ref_mut<'_>> {
+                if index >= self.len() {
+                    return None;
+                }
+                Some($ref_mut {
+                    $($field: &mut self.$field[index]),+
+                })
+            }
+
+            $(
+                #[doc = concat!("Returns the ", stringify!($field), " column as a slice")]
+                $field_vis fn $field(&self) -> &[$field_ty] {
+                    self.$field.as_slice()
+                }
+            )+
+
+            ///Removes row index and puts its fields back together, moving the last row into its place.
+            ///O(1) but does not preserve ordering. Panics if index is out of bounds
+            pub fn swap_remove(&mut self, index: usize) -> $name {
+                //every column has the same length, so only the first swap_remove can panic and it does
+                //before changing anything
+                $name {
+                    $($field: self.$field.swap_remove(index)),+
+                }
+            }
+
+            ///Sorts the rows by the key f returns for each. Every column is moved the same way so the rows
+            ///stay together. The sort is stable
+            pub fn sort_by_key<K, F>(&mut self, mut f: F)
+            where
+                K: Ord,
+                F: FnMut($ref_name<'_>) -> K,
+            {
+                //sorts the row numbers first so a panicking f leaves the columns as they were
+                let mut order: $crate::josie_vec::JosieVec<usize> = (0..self.len()).collect();
+                order.sort_by_key(|&index| f($ref_name { $($field: &self.$field[index]),+ }));
+                $($crate::josie_soa::apply_permutation(&mut self.$field, &order);)+
+            }
+
+            ///Drops every row past len
+            pub fn truncate(&mut self, len: usize) {
+                $(self.$field.truncate(len);)+
+            }
+
+            ///Drops every row, keeping the allocations
+            pub fn clear(&mut self) {
+                $(self.$field.clear();)+
+            }
+
+            ///Returns an iterator over references to the rows
+            pub fn iter(&self) -> $crate::josie_soa::JosieSoaIterRef<'_, Self> {
+                $crate::josie_soa::JosieSoaIterRef::new(self)
+            }
+
+            ///Returns an iterator over mutable references to the rows
+            pub fn iter_mut(&mut self) -> $crate::josie_soa::JosieSoaIterMut<'_, Self> {
+                $crate::josie_soa::JosieSoaIterMut::new(self)
+            }
+        }
+
+        impl $crate::josie_soa::JosieSoa for $soa {
+            type Ref<'a> = $ref_name<'a>;
+            type RefMut<'a> = $ref_mut<'a>;
+            type ColumnsMut<'a> = ($(::std::slice::IterMut<'a, $field_ty>,)+);
+
+            fn len(&self) -> usize {
+                self.len()
+            }
+
+            fn row(&self, index: usize) -> $ref_name<'_> {
+                $ref_name {
+                    $($field: &self.$field[index]),+
+                }
+            }
+
+            fn columns_mut(&mut self) -> Self::ColumnsMut<'_> {
+                ($(self.$field.as_mut_slice().iter_mut(),)+)
+            }
+
+            fn next_row_mut<'a>(columns: &mut Self::ColumnsMut<'a>) -> Option<$ref_mut<'a>> {
+                let ($($field,)+) = columns;
+                Some($ref_mut {
+                    $($field: $field.next()?),+
+                })
+            }
+
+            fn next_back_row_mut<'a>(columns: &mut Self::ColumnsMut<'a>) -> Option<$ref_mut<'a>> {
+                let ($($field,)+) = columns;
+                Some($ref_mut {
+                    $($field: $field.next_back()?),+
+                })
+            }
+        }
+
+        impl ::std::default::Default for $soa {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl ::std::iter::Extend<$name> for $soa {
+            fn extend<I>(&mut self, iter: I)
+            where
+                I: ::std::iter::IntoIterator<Item = $name>,
+            {
+                let iter = iter.into_iter();
+                self.reserve(iter.size_hint().0);
+                for value in iter {
+                    self.push(value);
+                }
+            }
+        }
+
+        impl ::std::iter::FromIterator<$name> for $soa {
+            fn from_iter<I>(iter: I) -> Self
+            where
+                I: ::std::iter::IntoIterator<Item = $name>,
+            {
+                let mut soa = Self::new();
+                soa.extend(iter);
+                soa
+            }
+        }
+
+        impl<'a> ::std::iter::IntoIterator for &'a $soa {
+            type Item = $ref_name<'a>;
+            type IntoIter = $crate::josie_soa::JosieSoaIterRef<'a, $soa>;
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl<'a> ::std::iter::IntoIterator for &'a mut $soa {
+            type Item = $ref_mut<'a>;
+            type IntoIter = $crate::josie_soa::JosieSoaIterMut<'a, $soa>;
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+    };
+}
+
+///Implemented by the containers josie_soa! makes so the iterators in this module can walk their rows
+pub trait JosieSoa {
+    ///References to the fields of one row
+    type Ref<'a>
+    where
+        Self: 'a;
+    ///Mutable references to the fields of one row
+    type RefMut<'a>
+    where
+        Self: 'a;
+    ///One mutable iterator per column, advanced together to make rows
+    type ColumnsMut<'a>
+    where
+        Self: 'a;
+
+    ///Number of rows
+    fn len(&self) -> usize;
+
+    ///Returns true if there are no rows
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    ///Returns references to the fields of row index. Panics if index is out of bounds
+    fn row(&self, index: usize) -> Self::Ref<'_>;
+
+    ///Returns a mutable iterator over every column
+    fn columns_mut(&mut self) -> Self::ColumnsMut<'_>;
+
+    ///Takes the next row off the front of the column iterators
+    fn next_row_mut<'a>(columns: &mut Self::ColumnsMut<'a>) -> Option<Self::RefMut<'a>>
+    where
+        Self: 'a;
+
+    ///Takes the next row off the back of the column iterators
+    fn next_back_row_mut<'a>(columns: &mut Self::ColumnsMut<'a>) -> Option<Self::RefMut<'a>>
+    where
+        Self: 'a;
+}
+
+///Moves column[order[i]] to column[i] for every i by following the cycles of the permutation, so every
+///element is moved once. Used by the sort_by_key josie_soa! makes, which applies the same order to
+///every column
+#[doc(hidden)]
+pub fn apply_permutation<T>(column: &mut [T], order: &[usize]) {
+    let mut done = JosieVec::from_iter(std::iter::repeat_n(false, order.len()));
+    for start in 0..order.len() {
+        if done[start] {
+            continue;
+        }
+        //each swap puts the right element at current and carries the one from start along the cycle
+        let mut current = start;
+        loop {
+            done[current] = true;
+            let next = order[current];
+            if next == start {
+                break;
+            }
+            column.swap(current, next);
+            current = next;
+        }
+    }
+}
+
+// ===============================
+// ITERATORS
+// -------------------------------
+
+///Iterator over references to the rows of a josie_soa! container
+pub struct JosieSoaIterRef<'a, S: JosieSoa> {
+    soa: &'a S,
+    //next row from the front and one past the next row from the back
+    front: usize,
+    back: usize,
+}
+
+impl<'a, S: JosieSoa> JosieSoaIterRef<'a, S> {
+    ///Creates an iterator over every row of soa
+    pub fn new(soa: &'a S) -> Self {
+        Self {
+            soa,
+            front: 0,
+            back: soa.len(),
+        }
+    }
+}
+
+impl<S: JosieSoa> Clone for JosieSoaIterRef<'_, S> {
+    fn clone(&self) -> Self {
+        Self {
+            soa: self.soa,
+            front: self.front,
+            back: self.back,
+        }
+    }
+}
+
+impl<'a, S: JosieSoa> Iterator for JosieSoaIterRef<'a, S> {
+    type Item = S::Ref<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.soa.row(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.back - self.front;
+        (size, Some(size))
+    }
+}
+
+impl<S: JosieSoa> DoubleEndedIterator for JosieSoaIterRef<'_, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.soa.row(self.back))
+    }
+}
+
+impl<S: JosieSoa> ExactSizeIterator for JosieSoaIterRef<'_, S> {}
+impl<S: JosieSoa> FusedIterator for JosieSoaIterRef<'_, S> {}
+
+///Iterator over mutable references to the rows of a josie_soa! container
+pub struct JosieSoaIterMut<'a, S: JosieSoa + 'a> {
+    columns: S::ColumnsMut<'a>,
+    remaining: usize,
+}
+
+impl<'a, S: JosieSoa> JosieSoaIterMut<'a, S> {
+    ///Creates an iterator over every row of soa
+    pub fn new(soa: &'a mut S) -> Self {
+        Self {
+            remaining: soa.len(),
+            columns: soa.columns_mut(),
+        }
+    }
+}
+
+impl<'a, S: JosieSoa> Iterator for JosieSoaIterMut<'a, S> {
+    type Item = S::RefMut<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        S::next_row_mut(&mut self.columns)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<S: JosieSoa> DoubleEndedIterator for JosieSoaIterMut<'_, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        S::next_back_row_mut(&mut self.columns)
+    }
+}
+
+impl<S: JosieSoa> ExactSizeIterator for JosieSoaIterMut<'_, S> {}
+impl<S: JosieSoa> FusedIterator for JosieSoaIterMut<'_, S> {}
@@ -0,0 +1,370 @@
+// ===============================
+//       JOSIE SORTED VEC
+// -------------------------------
+// Sorted set stored flat in a josievec. Lookups are binary searches over contiguous memory, which
+// beats a hash table or a tree for small sets that are read far more than they are written. Inserting
+// and removing shift the tail of the josievec so they are O(n). The elements are only ever handed out
+// behind shared references, so nothing outside this file can break the order
+// -------------------------------
+
+use std::{
+    borrow::Borrow,
+    fmt,
+    hash::{Hash, Hasher},
+    mem::replace,
+    ops::{Bound, Deref, Range, RangeBounds},
+    slice,
+};
+
+use crate::josie_vec::{
+    josievec_drain::JosieVecDrain, josievec_error::JosieTryReserveError,
+    josievec_iter::JosieVecIter, JosieVec,
+};
+
+///Set of unique elements kept in ascending order in a josievec
+pub struct JosieSortedVec<T: Ord> {
+    data: JosieVec<T>,
+}
+
+impl<T: Ord> JosieSortedVec<T> {
+    ///Creates an empty JosieSortedVec without allocating
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self {
+            data: JosieVec::new(),
+        }
+    }
+
+    ///Creates an empty JosieSortedVec with room for cap elements
+    pub fn with_capacity(cap: usize) -> Self {
+        Self {
+            data: JosieVec::with_capacity(cap),
+        }
+    }
+
+    ///Sorts josievec and drops every element equal to one before it, keeping the first of each. Reuses
+    ///the allocation. O(n log n)
+    pub fn from_unsorted(mut josievec: JosieVec<T>) -> Self {
+        //the sort is stable so the element kept out of each run of equal ones is the one that came first
+        josievec.sort();
+        josievec.dedup();
+        Self { data: josievec }
+    }
+
+    ///Number of elements
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    ///Returns true if there are no elements
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    ///Number of elements that fit before the josievec reallocates
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    ///Reserves room for at least additional more elements
+    #[inline(always)]
+    pub fn reserve(&mut self, additional: usize) {
+        self.data.reserve(additional)
+    }
+
+    ///Reserves room for at least additional more elements, returning an error instead of panicking or
+    ///aborting if the josievec can not grow
+    #[inline(always)]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), JosieTryReserveError> {
+        self.data.try_reserve(additional)
+    }
+
+    ///Shrinks the capacity to fit the elements there are
+    #[inline(always)]
+    pub fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit()
+    }
+
+    ///Returns the elements in ascending order
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_slice()
+    }
+
+    ///Returns an iterator over the elements in ascending order
+    #[inline(always)]
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.data.as_slice().iter()
+    }
+
+    ///Returns the position of value, or Err with the position it would be inserted at
+    pub fn search<Q>(&self, value: &Q) -> Result<usize, usize>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.data
+            .binary_search_by(|element| element.borrow().cmp(value))
+    }
+
+    ///Returns the position of value
+    pub fn get_index_of<Q>(&self, value: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.search(value).ok()
+    }
+
+    ///Returns true if the set holds value. O(log n)
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.search(value).is_ok()
+    }
+
+    ///Returns the stored element equal to value
+    pub fn get<Q>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.search(value).ok().map(|index| &self.data[index])
+    }
+
+    ///Returns the elements that fall in range, in ascending order. A range that ends before it starts
+    ///is empty. O(log n)
+    pub fn range<Q, R>(&self, range: R) -> &[T]
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        &self.data[sorted_range(&self.data, range, T::borrow)]
+    }
+
+    ///Inserts value where it belongs, returning false if an equal element was already there. The stored
+    ///element is kept. O(n)
+    pub fn insert(&mut self, value: T) -> bool {
+        match self.search(&value) {
+            Ok(_) => false,
+            Err(index) => {
+                self.data.insert(index, value);
+                true
+            }
+        }
+    }
+
+    ///Inserts value where it belongs, returning the stored element it replaced
+    pub fn replace(&mut self, value: T) -> Option<T> {
+        match self.search(&value) {
+            Ok(index) => Some(replace(&mut self.data[index], value)),
+            Err(index) => {
+                self.data.insert(index, value);
+                None
+            }
+        }
+    }
+
+    ///Removes value, returning true if it was there. O(n)
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.take(value).is_some()
+    }
+
+    ///Removes value and returns the stored element. O(n)
+    pub fn take<Q>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(value).ok()?;
+        Some(self.data.remove(index))
+    }
+
+    ///Removes and returns the element at position index. Panics if index is out of bounds
+    #[inline(always)]
+    pub fn remove_index(&mut self, index: usize) -> T {
+        self.data.remove(index)
+    }
+
+    ///Removes and returns the smallest element. O(n)
+    pub fn pop_first(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(self.data.remove(0))
+    }
+
+    ///Removes and returns the greatest element. O(1)
+    #[inline(always)]
+    pub fn pop_last(&mut self) -> Option<T> {
+        self.data.pop()
+    }
+
+    ///Keeps only the elements the predicate returns true for
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.data.retain(f)
+    }
+
+    ///Removes the elements at the positions in range and returns them in ascending order
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> JosieVecDrain<'_, T> {
+        self.data.drain(range)
+    }
+
+    ///Drops every element, keeping the allocation
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.data.clear()
+    }
+
+    ///Returns the josievec the elements are stored in, in ascending order
+    #[inline(always)]
+    pub fn into_josievec(self) -> JosieVec<T> {
+        self.data
+    }
+}
+
+//positions of the entries whose keys fall in range, found with two binary searches. entries has to be
+//sorted by key
+pub(crate) fn sorted_range<E, Q, R>(entries: &[E], range: R, key: impl Fn(&E) -> &Q) -> Range<usize>
+where
+    Q: Ord + ?Sized,
+    R: RangeBounds<Q>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(start) => entries.partition_point(|entry| key(entry) < start),
+        Bound::Excluded(start) => entries.partition_point(|entry| key(entry) <= start),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => entries.partition_point(|entry| key(entry) <= end),
+        Bound::Excluded(end) => entries.partition_point(|entry| key(entry) < end),
+        Bound::Unbounded => entries.len(),
+    };
+    start..end.max(start)
+}
+
+// ===============================
+// TRAITS
+// -------------------------------
+
+//only shared access, a mutable slice could be reordered
+impl<T: Ord> Deref for JosieSortedVec<T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+//sorts and dedups the josievec in place
+impl<T: Ord> From<JosieVec<T>> for JosieSortedVec<T> {
+    fn from(josievec: JosieVec<T>) -> Self {
+        Self::from_unsorted(josievec)
+    }
+}
+
+impl<T: Ord, const N: usize> From<[T; N]> for JosieSortedVec<T> {
+    fn from(array: [T; N]) -> Self {
+        Self::from_iter(array)
+    }
+}
+
+impl<T: Ord> From<JosieSortedVec<T>> for JosieVec<T> {
+    fn from(sorted: JosieSortedVec<T>) -> Self {
+        sorted.data
+    }
+}
+
+impl<T: Ord> Default for JosieSortedVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Clone> Clone for JosieSortedVec<T> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<T: Ord + fmt::Debug> fmt::Debug for JosieSortedVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Ord> PartialEq for JosieSortedVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Ord> Eq for JosieSortedVec<T> {}
+
+impl<T: Ord + Hash> Hash for JosieSortedVec<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T: Ord> Extend<T> for JosieSortedVec<T> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.data.extend(iter);
+        //the stable sort finds the sorted run already there, and keeps stored elements ahead of equal new
+        //ones so the dedup keeps the stored ones like insert does
+        self.data.sort();
+        self.data.dedup();
+    }
+}
+
+impl<'a, T: Ord + Copy + 'a> Extend<&'a T> for JosieSortedVec<T> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = &'a T>,
+    {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+impl<T: Ord> FromIterator<T> for JosieSortedVec<T> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::from_unsorted(JosieVec::from_iter(iter))
+    }
+}
+
+//yields the elements in ascending order
+impl<T: Ord> IntoIterator for JosieSortedVec<T> {
+    type Item = T;
+    type IntoIter = JosieVecIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T: Ord> IntoIterator for &'a JosieSortedVec<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
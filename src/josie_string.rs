@@ -0,0 +1,430 @@
+// ===============================
+//       JOSIESTRING
+// -------------------------------
+// UTF-8 string stored in a JosieVec<u8>. Every method that can change the bytes only ever writes
+// whole chars or cuts on char boundaries, so the contents are always valid UTF-8 and can be handed
+// out as a str for free
+// -------------------------------
+
+use std::{
+    borrow::Borrow,
+    error::Error,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Add, AddAssign, Deref, DerefMut},
+    ptr::{copy, copy_nonoverlapping},
+    str::{from_utf8, from_utf8_unchecked, from_utf8_unchecked_mut, Utf8Error},
+};
+
+use crate::josie_vec::JosieVec;
+
+///Growable UTF-8 string backed by a JosieVec<u8>
+#[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct JosieString {
+    vec: JosieVec<u8>,
+}
+
+///Error returned by JosieString::from_utf8 when the bytes arent valid UTF-8. Holds on to the bytes so they
+///can be taken back
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JosieFromUtf8Error {
+    bytes: JosieVec<u8>,
+    error: Utf8Error,
+}
+
+impl JosieFromUtf8Error {
+    ///Returns the bytes that were passed to from_utf8
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    ///Hands back the bytes that were passed to from_utf8
+    pub fn into_bytes(self) -> JosieVec<u8> {
+        self.bytes
+    }
+
+    ///Returns the underlying error, which says where the invalid UTF-8 starts
+    pub fn utf8_error(&self) -> Utf8Error {
+        self.error
+    }
+}
+
+impl fmt::Display for JosieFromUtf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl Error for JosieFromUtf8Error {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl JosieString {
+    ///Creates an empty JosieString without allocating
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///Creates an empty JosieString with room for cap bytes
+    pub fn with_capacity(cap: usize) -> Self {
+        Self {
+            vec: JosieVec::with_capacity(cap),
+        }
+    }
+
+    ///Turns a byte josievec into a JosieString without copying, returning an error holding the bytes if they
+    ///arent valid UTF-8
+    pub fn from_utf8(vec: JosieVec<u8>) -> Result<Self, JosieFromUtf8Error> {
+        match from_utf8(&vec) {
+            Ok(_) => Ok(Self { vec }),
+            Err(error) => Err(JosieFromUtf8Error { bytes: vec, error }),
+        }
+    }
+
+    ///Turns a byte josievec into a JosieString without checking it.
+    ///
+    /// # Safety
+    ///vec must be valid UTF-8.
+    #[inline(always)]
+    pub unsafe fn from_utf8_unchecked(vec: JosieVec<u8>) -> Self {
+        Self { vec }
+    }
+
+    ///Returns the contents as a str
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        unsafe { from_utf8_unchecked(&self.vec) }
+    }
+
+    ///Returns the contents as a mutable str
+    #[inline(always)]
+    pub fn as_mut_str(&mut self) -> &mut str {
+        unsafe { from_utf8_unchecked_mut(&mut self.vec) }
+    }
+
+    ///Returns the contents as bytes
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.vec
+    }
+
+    ///Returns the byte josievec the string is stored in.
+    ///
+    /// # Safety
+    ///The bytes must still be valid UTF-8 when the borrow ends.
+    #[inline(always)]
+    pub unsafe fn as_mut_josievec(&mut self) -> &mut JosieVec<u8> {
+        &mut self.vec
+    }
+
+    ///Returns the byte josievec the string is stored in
+    #[inline(always)]
+    pub fn into_bytes(self) -> JosieVec<u8> {
+        self.vec
+    }
+
+    ///Length in bytes
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    ///Returns true if the string is empty
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    ///Number of bytes that fit before the string reallocates
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    ///Reserves room for at least additional more bytes
+    #[inline(always)]
+    pub fn reserve(&mut self, additional: usize) {
+        self.vec.reserve(additional)
+    }
+
+    ///Shrinks the capacity to the length
+    #[inline(always)]
+    pub fn shrink_to_fit(&mut self) {
+        self.vec.shrink_to_fit()
+    }
+
+    ///Appends a char to the end
+    pub fn push(&mut self, ch: char) {
+        self.push_str(ch.encode_utf8(&mut [0; 4]))
+    }
+
+    ///Appends a str to the end
+    #[inline]
+    pub fn push_str(&mut self, string: &str) {
+        self.vec.extend_from_copy_slice(string.as_bytes())
+    }
+
+    ///Removes and returns the last char
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.chars().next_back()?;
+        let new_len = self.len() - ch.len_utf8();
+        unsafe { self.vec.set_len(new_len) };
+        Some(ch)
+    }
+
+    ///Inserts a char at byte index idx. Panics if idx is not on a char boundary
+    pub fn insert(&mut self, idx: usize, ch: char) {
+        self.insert_str(idx, ch.encode_utf8(&mut [0; 4]))
+    }
+
+    ///Inserts a str at byte index idx. Panics if idx is not on a char boundary
+    pub fn insert_str(&mut self, idx: usize, string: &str) {
+        if !self.is_char_boundary(idx) {
+            panic!("Tried to insert at byte {idx} which is not a char boundary")
+        }
+        let (len, amount) = (self.len(), string.len());
+        self.vec.reserve(amount);
+        unsafe {
+            let ptr = self.vec.as_mut_ptr();
+            //opens a gap at idx then copies the new bytes into it
+            copy(ptr.add(idx), ptr.add(idx + amount), len - idx);
+            copy_nonoverlapping(string.as_ptr(), ptr.add(idx), amount);
+            self.vec.set_len(len + amount);
+        }
+    }
+
+    ///Removes and returns the char starting at byte index idx. Panics if idx is not on a char boundary or is
+    ///past the end
+    pub fn remove(&mut self, idx: usize) -> char {
+        let ch = match self.get(idx..).and_then(|rest| rest.chars().next()) {
+            Some(ch) => ch,
+            None => panic!("Tried to remove at byte {idx} which is not the start of a char"),
+        };
+        let (len, amount) = (self.len(), ch.len_utf8());
+        unsafe {
+            let ptr = self.vec.as_mut_ptr();
+            copy(ptr.add(idx + amount), ptr.add(idx), len - idx - amount);
+            self.vec.set_len(len - amount);
+        }
+        ch
+    }
+
+    ///Shortens the string to new_len bytes. Does nothing if new_len is past the end. Panics if new_len is not
+    ///on a char boundary
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len() {
+            if !self.is_char_boundary(new_len) {
+                panic!("Tried to truncate at byte {new_len} which is not a char boundary")
+            }
+            self.vec.truncate(new_len)
+        }
+    }
+
+    ///Empties the string, keeping the capacity
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.vec.clear()
+    }
+}
+
+impl Deref for JosieString {
+    type Target = str;
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl DerefMut for JosieString {
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl fmt::Display for JosieString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Debug for JosieString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+//lets write! and format_args write straight into the josiestring
+impl fmt::Write for JosieString {
+    #[inline]
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        self.push_str(string);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_char(&mut self, ch: char) -> fmt::Result {
+        self.push(ch);
+        Ok(())
+    }
+}
+
+//hashes exactly like str so a josiestring key can be looked up with a str through Borrow
+impl Hash for JosieString {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl PartialEq<str> for JosieString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for JosieString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for JosieString {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<JosieString> for str {
+    fn eq(&self, other: &JosieString) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<JosieString> for &str {
+    fn eq(&self, other: &JosieString) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl Borrow<str> for JosieString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for JosieString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for JosieString {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Add<&str> for JosieString {
+    type Output = JosieString;
+    fn add(mut self, other: &str) -> JosieString {
+        self.push_str(other);
+        self
+    }
+}
+
+impl AddAssign<&str> for JosieString {
+    fn add_assign(&mut self, other: &str) {
+        self.push_str(other)
+    }
+}
+
+impl Extend<char> for JosieString {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = char>,
+    {
+        let iterator = iter.into_iter();
+        //every char is at least one byte
+        self.reserve(iterator.size_hint().0);
+        for ch in iterator {
+            self.push(ch);
+        }
+    }
+}
+
+impl<'a> Extend<&'a str> for JosieString {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        for string in iter {
+            self.push_str(string);
+        }
+    }
+}
+
+impl FromIterator<char> for JosieString {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = char>,
+    {
+        let mut temp = Self::new();
+        temp.extend(iter);
+        temp
+    }
+}
+
+impl<'a> FromIterator<&'a str> for JosieString {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut temp = Self::new();
+        temp.extend(iter);
+        temp
+    }
+}
+
+impl From<&str> for JosieString {
+    fn from(string: &str) -> Self {
+        let mut temp = Self::with_capacity(string.len());
+        temp.push_str(string);
+        temp
+    }
+}
+
+impl From<char> for JosieString {
+    fn from(ch: char) -> Self {
+        Self::from(ch.encode_utf8(&mut [0; 4]) as &str)
+    }
+}
+
+//takes over the buffer of the String without copying
+impl From<String> for JosieString {
+    fn from(string: String) -> Self {
+        Self {
+            vec: JosieVec::from(string.into_bytes()),
+        }
+    }
+}
+
+//hands the buffer to a String without copying
+impl From<JosieString> for String {
+    fn from(string: JosieString) -> Self {
+        unsafe { String::from_utf8_unchecked(string.vec.into()) }
+    }
+}
+
+impl From<JosieString> for JosieVec<u8> {
+    fn from(string: JosieString) -> Self {
+        string.vec
+    }
+}
+
+impl TryFrom<JosieVec<u8>> for JosieString {
+    type Error = JosieFromUtf8Error;
+    fn try_from(vec: JosieVec<u8>) -> Result<Self, Self::Error> {
+        Self::from_utf8(vec)
+    }
+}
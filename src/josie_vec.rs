@@ -6,32 +6,47 @@
 // -------------------------------
 
 use std::{
-    alloc::{alloc, dealloc, realloc, Layout},
-    mem::ManuallyDrop,
-    ops::{Deref, DerefMut},
-    ptr::{copy, drop_in_place, slice_from_raw_parts_mut, NonNull},
+    alloc::{handle_alloc_error, Layout},
+    mem::MaybeUninit,
+    ops::{Bound, Deref, DerefMut, Range, RangeBounds},
+    ptr::{copy, copy_nonoverlapping, drop_in_place, slice_from_raw_parts_mut, NonNull},
     slice::{from_raw_parts, from_raw_parts_mut},
 };
 
 //Declares the mods for josievec
+pub mod josievec_alloc;
+pub mod josievec_convert;
 pub mod josievec_drain;
+pub mod josievec_error;
 pub mod josievec_extend;
+pub mod josievec_extract_if;
+pub mod josievec_growth;
 pub mod josievec_iter;
+pub mod josievec_retain;
+pub mod josievec_splice;
+pub mod josievec_traits;
+pub mod josievec_writer;
+
+use josievec_alloc::{Global, JosieAllocator};
+use josievec_error::JosieTryReserveError;
+use josievec_growth::{Doubling, GrowthPolicy};
 
 ///JosieVec is the Vector
-#[derive(Debug)]
-pub struct JosieVec<T> {
-    pub(crate) buf: RawJosieVec<T>,
+pub struct JosieVec<T, A: JosieAllocator = Global, G: GrowthPolicy = Doubling> {
+    pub(crate) buf: RawJosieVec<T, A>,
     pub(crate) len: usize,
+    pub(crate) policy: G,
 }
 
-///RawJosieVec contains the nonnull pointer and the capacity
+///RawJosieVec contains the nonnull pointer, the capacity and the allocator that owns the buffer
 #[derive(Debug)]
-pub(crate) struct RawJosieVec<T> {
+pub(crate) struct RawJosieVec<T, A: JosieAllocator = Global> {
     pub(crate) ptr: NonNull<T>,
     pub(crate) cap: usize,
+    pub(crate) alloc: A,
 }
-//Public methods
+
+//Constructors using the global allocator and the default growth policy
 impl<T> JosieVec<T> {
     ///Constructor for JosieVec, Creates an uninitialized JosieVec with capacity of zero
     #[inline(always)]
@@ -42,25 +57,115 @@ impl<T> JosieVec<T> {
     ///Constructor for JosieVec with preallocated capacity. Creates a new JosieVec and Reallocs
     ///internal allocaton to be the capacity specified
     pub fn with_capacity(cap: usize) -> Self {
-        //creates new default josievec
-        let mut temp = Self::default();
+        Self::with_capacity_in(cap, Global)
+    }
+
+    ///Fallible version of with_capacity. Returns an error instead of panicking if the capacity
+    ///overflows or the allocator fails
+    pub fn try_with_capacity(cap: usize) -> Result<Self, JosieTryReserveError> {
+        Self::try_with_capacity_in(cap, Global)
+    }
+}
+
+//Constructors using the default growth policy
+impl<T, A: JosieAllocator> JosieVec<T, A> {
+    ///Constructor for JosieVec that allocates all of its memory from the allocator passed in
+    #[inline(always)]
+    pub const fn new_in(alloc: A) -> Self {
+        Self::with_policy_in(Doubling, alloc)
+    }
+
+    ///Constructor for JosieVec with preallocated capacity taken from the allocator passed in
+    pub fn with_capacity_in(cap: usize, alloc: A) -> Self {
+        //creates new empty josievec
+        let mut temp = Self::new_in(alloc);
         //runs realloc internal to realloc with capacirt specified in cap
         unsafe { temp.realloc_internal(cap) }
         //returns temp
         temp
     }
 
+    ///Fallible version of with_capacity_in. Returns an error instead of panicking if the capacity
+    ///overflows or the allocator fails
+    pub fn try_with_capacity_in(cap: usize, alloc: A) -> Result<Self, JosieTryReserveError> {
+        let mut temp = Self::new_in(alloc);
+        //allocates the exact capacity requested, handing any error back to the caller
+        unsafe { temp.buf.try_realloc(cap)? }
+        Ok(temp)
+    }
+}
+
+//Methods only available on the global allocator
+impl<T, G: GrowthPolicy> JosieVec<T, Global, G> {
+    ///Constructor for JosieVec that grows and shrinks according to the policy passed in
+    #[inline(always)]
+    pub const fn with_policy(policy: G) -> Self {
+        Self::with_policy_in(policy, Global)
+    }
+
+    ///Creates boxed slice from joseievec, shrinking the allocation to the length first. Only
+    ///available on the global allocator because Box can not carry a custom allocator on stable
+    #[inline(always)]
+    pub fn into_boxed_slice(mut self) -> Box<[T]> {
+        //Box deallocates with the layout of its length, so the capacity has to match it exactly
+        self.shrink_to_fit();
+        //takes ownership of the allocation so that drop doesnt run.
+        let (ptr, len, _) = self.into_raw_parts();
+        unsafe {
+            //creates new slice from the raw parts of josievex
+            Box::from_raw(slice_from_raw_parts_mut(ptr, len))
+        }
+    }
+}
+
+//Public methods
+impl<T, A: JosieAllocator, G: GrowthPolicy> JosieVec<T, A, G> {
+    ///Constructor for JosieVec that allocates from the allocator passed in and grows and shrinks
+    ///according to the policy passed in
+    #[inline(always)]
+    pub const fn with_policy_in(policy: G, alloc: A) -> Self {
+        Self {
+            buf: RawJosieVec::new_in(alloc),
+            len: 0,
+            policy,
+        }
+    }
+
+    ///Returns a reference to the allocator backing this JosieVec
+    #[inline(always)]
+    pub const fn allocator(&self) -> &A {
+        &self.buf.alloc
+    }
+
+    ///Returns a reference to the growth policy of this JosieVec
+    #[inline(always)]
+    pub const fn policy(&self) -> &G {
+        &self.policy
+    }
+
     ///Pushes an element to JosieVec, incrementing the length by one. If JosieVec is out of
-    ///capacity, triggers ammortized growth, doubleing the allocation of the vector
+    ///capacity, triggers ammortized growth, growing the allocation by the growth policy
     pub fn push(&mut self, element: T) {
         //if capacity is equal to length then double capacity
-        if self.buf.cap == self.len {
+        if self.buf.capacity() == self.len {
             //sets reserves memory equal to current 2 times the length
             self.grow_amortized();
         }
         unsafe { self.push_internal(element) }
     }
 
+    ///Fallible version of push. If growing the vector fails the element is handed back to the
+    ///caller along with the reason the allocation failed
+    pub fn try_push(&mut self, element: T) -> Result<(), (T, JosieTryReserveError)> {
+        if self.buf.capacity() == self.len
+            && let Err(err) = self.try_grow_amortized()
+        {
+            return Err((element, err));
+        }
+        unsafe { self.push_internal(element) }
+        Ok(())
+    }
+
     ///Pops last value from JosieVec. If there are no more elements left in the vector, returns
     ///None
     pub fn pop(&mut self) -> Option<T> {
@@ -70,8 +175,10 @@ impl<T> JosieVec<T> {
         }
         //decrements length counter by 1
         self.len -= 1;
-        //returns the element at the current length
-        Some(unsafe { self.buf.ptr.add(self.len).read() })
+        //reads out the element at the current length
+        let out = unsafe { self.buf.ptr.add(self.len).read() };
+        self.apply_shrink_policy();
+        Some(out)
     }
 
     ///Removes element at index, returns to the caller, and appends tail to make JosieVec
@@ -91,48 +198,144 @@ impl<T> JosieVec<T> {
                 self.buf.ptr.as_ptr().add(index),
                 self.len - index,
             );
+            self.apply_shrink_policy();
             //returns
-            return out;
+            out
+        }
+    }
+
+    ///Inserts element at index, shifting every element after it one to the right. Panics if index
+    ///is greater than the length
+    pub fn insert(&mut self, index: usize, element: T) {
+        if index > self.len {
+            panic!("Tried to insert out of bounds element")
+        }
+        //if capacity is equal to length then double capacity
+        if self.buf.capacity() == self.len {
+            self.grow_amortized();
+        }
+        unsafe {
+            let ptr = self.buf.ptr.as_ptr().add(index);
+            //copies the data from index to end of josievec one to the right to open a gap at index
+            copy(ptr, ptr.add(1), self.len - index);
+            //writes the element into the gap
+            ptr.write(element);
+        }
+        //increments length counter by one
+        self.len += 1;
+    }
+
+    ///Removes element at index and returns it, filling the gap with the last element. O(1) but does
+    ///not preserve ordering
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        if index >= self.len {
+            panic!("Tried to swap remove out of bounds element")
+        }
+        //decrements length counter by one, len is now the index of the last element
+        self.len -= 1;
+        unsafe {
+            let ptr = self.buf.ptr.as_ptr();
+            let out = ptr.add(index).read();
+            //moves the last element into the gap, index and len are the same if the last element was removed
+            copy(ptr.add(self.len), ptr.add(index), 1);
+            self.apply_shrink_policy();
+            out
         }
     }
 
+    ///Moves every element of other onto the end of this JosieVec, leaving other empty. The elements are moved with a
+    ///single memcpy
+    pub fn append(&mut self, other: &mut Self) {
+        self.reserve(other.len);
+        unsafe {
+            copy_nonoverlapping(
+                other.buf.ptr.as_ptr(),
+                self.buf.ptr.as_ptr().add(self.len),
+                other.len,
+            );
+        }
+        self.len += other.len;
+        //the elements are owned by self now so other must not drop them
+        other.len = 0;
+    }
+
+    ///Splits the JosieVec in two at index at. Self keeps the elements before at and the returned JosieVec,
+    ///allocated from a clone of the same allocator, gets the rest. Panics if at is greater than the length
+    pub fn split_off(&mut self, at: usize) -> Self
+    where
+        A: Clone,
+        G: Clone,
+    {
+        if at > self.len {
+            panic!("Tried to split off out of bounds")
+        }
+        let other_len = self.len - at;
+        let mut other = Self::with_policy_in(self.policy.clone(), self.buf.alloc.clone());
+        other.reserve_exact(other_len);
+        unsafe {
+            copy_nonoverlapping(self.buf.ptr.as_ptr().add(at), other.as_mut_ptr(), other_len);
+        }
+        //moves ownership of the elements over by moving the lengths
+        self.len = at;
+        other.len = other_len;
+        self.apply_shrink_policy();
+        other
+    }
+
     ///clears all elelmets on the JosieVec.
     #[inline(always)]
     pub fn clear(&mut self) {
         unsafe {
             //drops in place all elements in the vector
-            drop_in_place(from_raw_parts_mut(self.as_mut_ptr(), self.len));
+            drop_in_place(slice_from_raw_parts_mut(self.as_mut_ptr(), self.len));
         }
         //sets length to zero
         self.len = 0;
+        self.apply_shrink_policy();
     }
 
     ///Reserves at least enough capacity for the number of elements specified
     #[inline(always)]
     pub fn reserve(&mut self, cap: usize) {
-        //sets new capacity variable to be equal to current capacity
-        let mut new_capacity: usize = self.buf.cap;
-        //while the current length plus the capacity is greater than the new capacity keep doubling until you get a capacity that is greater
-        while self.len + cap > new_capacity {
-            match new_capacity {
-                //new capacity is set to one if size is currently zero
-                0 => new_capacity = 1,
-                //new capacity is bitshifted by one if it anything else
-                _ => new_capacity <<= 1,
-            }
+        if let Err(err) = self.try_reserve(cap) {
+            handle_reserve_error(err)
         }
-        //reallocs with the value of new capacity
-        unsafe { self.realloc_internal(new_capacity) }
     }
 
     ///Reserves capacity for exactly this number of elements
     #[inline(always)]
     pub fn reserve_exact(&mut self, cap: usize) {
-        if cap > self.buf.cap {
+        if cap > self.buf.capacity() {
             unsafe { self.realloc_internal(cap) }
         }
     }
 
+    ///Fallible version of reserve. cap counts additional elements on top of len. Grows the capacity
+    ///by the growth policy until there is room for len + cap elements, returning an error instead of
+    ///panicking if the capacity overflows or the allocator fails
+    pub fn try_reserve(&mut self, cap: usize) -> Result<(), JosieTryReserveError> {
+        //the total capacity needed to fit the current elements plus the extra elements
+        let required = self
+            .len
+            .checked_add(cap)
+            .ok_or(JosieTryReserveError::CapacityOverflow)?;
+        //returns early if there is already enough room
+        if required <= self.buf.capacity() {
+            return Ok(());
+        }
+        self.try_grow_to(required)
+    }
+
+    ///Fallible version of reserve_exact. Unlike try_reserve, cap is the absolute capacity wanted, not a
+    ///count of elements beyond len. Reallocates to exactly cap if the current capacity is smaller,
+    ///returning an error instead of panicking
+    pub fn try_reserve_exact(&mut self, cap: usize) -> Result<(), JosieTryReserveError> {
+        if cap > self.buf.capacity() {
+            unsafe { self.buf.try_realloc(cap)? }
+        }
+        Ok(())
+    }
+
     ///Shrinks JosieVec
     #[inline(always)]
     pub fn shrink_to(&mut self, cap: usize) {
@@ -149,6 +352,9 @@ impl<T> JosieVec<T> {
 
     ///Unsafe Function: Sets len to a specified length. unsafe because JosieVec cant uphold
     ///invariants regading whether or not len corresponds to real length.
+    ///
+    /// # Safety
+    ///len must be less than or equal to capacity and every element up to len must be initialized.
     #[inline(always)]
     pub unsafe fn set_len(&mut self, len: usize) {
         self.len = len;
@@ -159,12 +365,13 @@ impl<T> JosieVec<T> {
     pub fn truncate(&mut self, len: usize) {
         if len < self.len {
             unsafe {
-                drop_in_place(from_raw_parts_mut(
+                drop_in_place(slice_from_raw_parts_mut(
                     self.buf.ptr.as_ptr().add(len),
                     self.len - len,
                 ));
             }
             self.len = len;
+            self.apply_shrink_policy();
         }
     }
 
@@ -180,14 +387,15 @@ impl<T> JosieVec<T> {
         unsafe { from_raw_parts_mut(self.as_mut_ptr(), self.len) }
     }
 
-    ///Creates boxed slice from joseievec
+    ///Returns the capacity past the end of the JosieVec as a slice of uninitialized elements. Writing to it
+    ///doesnt change the length, use set_len afterwards or JosieVec::writer to have the length kept for you
     #[inline(always)]
-    pub fn into_boxed_slice(self) -> Box<[T]> {
-        //sets self to manually drop so that drop doesnt run.
-        let mut drop = ManuallyDrop::new(self);
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
         unsafe {
-            //creates new slice from the raw parts of josievex
-            return Box::from_raw(slice_from_raw_parts_mut(drop.as_mut_ptr(), drop.len));
+            from_raw_parts_mut(
+                self.buf.ptr.as_ptr().add(self.len) as *mut MaybeUninit<T>,
+                self.buf.capacity() - self.len,
+            )
         }
     }
 
@@ -197,10 +405,17 @@ impl<T> JosieVec<T> {
         self.len
     }
 
-    ///Outputs current capacity
+    ///Returns true if JosieVec holds no elements
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    ///Outputs current capacity. Zero sized types never need an allocation so their capacity is
+    ///always usize::MAX
     #[inline(always)]
     pub const fn capacity(&self) -> usize {
-        self.buf.cap
+        self.buf.capacity()
     }
 
     ///Exposes JosieVec Raw pointer
@@ -209,12 +424,12 @@ impl<T> JosieVec<T> {
     }
     ///Exposes Mutable Pointer to JosieVec buffer
     pub const fn as_mut_ptr(&mut self) -> *mut T {
-        unsafe { self.buf.ptr.as_mut() }
+        self.buf.ptr.as_ptr()
     }
 }
 
 //Internal Methods
-impl<T> JosieVec<T> {
+impl<T, A: JosieAllocator, G: GrowthPolicy> JosieVec<T, A, G> {
     ///Pushes withoug any checks. Bounds Checks are iplemented in public facing methods
     #[inline(always)]
     unsafe fn push_internal(&mut self, element: T) {
@@ -227,89 +442,286 @@ impl<T> JosieVec<T> {
         self.len += 1;
     }
 
-    ///Grows the vector by power of 2
+    ///Grows the vector by the growth policy
     #[inline]
     fn grow_amortized(&mut self) {
-        //if capacity is 0 then set capacity to 1
-        if self.buf.cap == 0 {
-            unsafe { self.realloc_internal(1) }
+        if let Err(err) = self.try_grow_amortized() {
+            handle_reserve_error(err)
+        }
+    }
+
+    ///Fallible version of grow_amortized. Asks the growth policy for room for at least one more
+    ///element than the current capacity
+    #[inline]
+    fn try_grow_amortized(&mut self) -> Result<(), JosieTryReserveError> {
+        let required = self
+            .buf
+            .capacity()
+            .checked_add(1)
+            .ok_or(JosieTryReserveError::CapacityOverflow)?;
+        self.try_grow_to(required)
+    }
+
+    ///Grows the capacity to whatever the growth policy picks for at least required elements
+    fn try_grow_to(&mut self, required: usize) -> Result<(), JosieTryReserveError> {
+        let new_capacity = self
+            .policy
+            .grow(self.buf.capacity(), required, size_of::<T>())
+            .ok_or(JosieTryReserveError::CapacityOverflow)?
+            //never trusts a policy to hand back enough room
+            .max(required);
+        unsafe { self.buf.try_realloc(new_capacity) }
+    }
+
+    ///Asks the growth policy if the buffer should shrink now that elements have been removed.
+    ///Shrinking is only an optimization so if the allocator fails the buffer is just kept as is
+    #[inline(always)]
+    pub(crate) fn apply_shrink_policy(&mut self) {
+        if is_zst::<T>() {
             return;
         }
-        //bitshifts self capacity one to the left to double the value
-        unsafe {
-            self.realloc_internal(self.buf.cap << 1);
+        if let Some(new_capacity) = self.policy.shrink(self.len, self.buf.cap, size_of::<T>()) {
+            let new_capacity = new_capacity.max(self.len);
+            if new_capacity < self.buf.cap {
+                let _ = unsafe { self.buf.try_realloc(new_capacity) };
+            }
         }
     }
 
-    ///Reallocates the Underlying JosieVec allocation
+    ///Reallocates the Underlying JosieVec allocation, panicking on capacity overflow and
+    ///handing allocator failures to handle_alloc_error
     unsafe fn realloc_internal(&mut self, cap: usize) {
+        if let Err(err) = unsafe { self.buf.try_realloc(cap) } {
+            handle_reserve_error(err)
+        }
+    }
+}
+
+impl<T, A: JosieAllocator> RawJosieVec<T, A> {
+    ///Creates an empty buffer with a dangling pointer that allocates from alloc when it grows
+    #[inline(always)]
+    pub(crate) const fn new_in(alloc: A) -> Self {
+        Self {
+            ptr: NonNull::dangling(),
+            cap: 0,
+            alloc,
+        }
+    }
+
+    ///Capacity of the buffer. Zero sized types never allocate, so every one of them fits in the
+    ///dangling pointer and the capacity is usize::MAX
+    #[inline(always)]
+    pub(crate) const fn capacity(&self) -> usize {
+        if is_zst::<T>() { usize::MAX } else { self.cap }
+    }
+
+    ///Reallocates the buffer to hold exactly cap elements. Never panics, any overflow in the
+    ///size calculation or failure from the allocator is returned as an error and leaves the
+    ///buffer untouched.
+    pub(crate) unsafe fn try_realloc(&mut self, cap: usize) -> Result<(), JosieTryReserveError> {
+        //zero sized types live entirely in the dangling pointer, allocating a zero sized layout is
+        //undefined behavior so there is never anything to do
+        if is_zst::<T>() {
+            return Ok(());
+        }
+        //the only case this runs is if the current size is zero and you are requesting a resize to zero.
+        //nothing to do so return straight away
+        if cap == 0 && self.cap == 0 {
+            return Ok(());
+        }
+        //creates the layout for the new capacity. errors on overflow of isize::MAX bytes
+        let new_layout =
+            Layout::array::<T>(cap).map_err(|_| JosieTryReserveError::CapacityOverflow)?;
         unsafe {
             //Reallocs Sets josievec Pointer to be the result of this match statement
 
             //If current cap == 0 and the capacity set to is not equal to zero then allocate a brand new pointer for the JosieVec
-            self.buf.ptr = if cap != 0 && self.buf.cap == 0 {
-                NonNull::new(
-                    //initializes new memory allocation for the josievec
-                    alloc(Layout::array::<T>(cap).expect("Overflow")) as *mut T,
-                )
-            }
-            //if the capacity of self is not zero realloc with the size specified. The only way in which it is
-            //possible to have a dangling pointer is if the capacity is equal to zero so this is always a safe operation
-            else if cap != 0 {
-                //creates new non null pointer
-                NonNull::new(
-                    //reallocates existing memory allocation
-                    realloc(
-                        self.buf.ptr.as_ptr() as *mut u8,
-                        Layout::array::<T>(self.buf.cap).expect("Overflow"),
-                        cap * size_of::<T>(),
-                    ) as *mut T,
-                )
+            let new_ptr = if self.cap == 0 {
+                //initializes new memory allocation for the josievec
+                self.alloc.allocate(new_layout)
             }
             //if you are reallocating to zero, dealloc the current pointer and then create a new non null dangling pointer
-            else if cap == 0 && self.buf.cap != 0 {
-                dealloc(
-                    self.buf.ptr.as_ptr() as *mut u8,
-                    Layout::array::<T>(self.buf.cap).expect("Overflow"),
-                );
-                Some(NonNull::dangling())
+            else if cap == 0 {
+                self.alloc
+                    .deallocate(self.ptr.cast(), self.current_layout());
+                Some(NonNull::<T>::dangling().cast())
             }
-            //the only case this runs is if the current size is zero and you are requesting a resize to zero.
-            //in this case just return the already existing pointer in the struct, no need to construct a new one. if this path is taken,
-            //I imagine llvm would optimize it away though granted i have no way of proving that without examining machine code which i will not do
+            //if the capacity of self is not zero realloc with the size specified. The only way in which it is
+            //possible to have a dangling pointer is if the capacity is equal to zero so this is always a safe operation
             else {
-                Some(self.buf.ptr)
-            }
-            .unwrap_or_else(|| panic!("Tried to realloc with a null pointer"));
-            //if NonNull tries to create itself and is given a null pointer then program panics
+                //reallocates existing memory allocation
+                self.alloc
+                    .reallocate(self.ptr.cast(), self.current_layout(), new_layout)
+            };
+            //if the allocator hands back a null pointer the old allocation is still valid, so report the error
+            //and leave the buffer as it was
+            self.ptr = new_ptr
+                .ok_or(JosieTryReserveError::AllocError { layout: new_layout })?
+                .cast();
         }
         //sets capacity to the new cap
-        self.buf.cap = cap;
+        self.cap = cap;
+        Ok(())
+    }
+
+    ///Layout of the current allocation. Always valid because it was checked when the buffer was allocated
+    #[inline(always)]
+    pub(crate) fn current_layout(&self) -> Layout {
+        unsafe { Layout::from_size_align_unchecked(size_of::<T>() * self.cap, align_of::<T>()) }
     }
 }
 
-impl<T> Deref for JosieVec<T> {
+///Turns an allocation error into the same panic or abort the infallible api has always had
+#[cold]
+#[inline(never)]
+pub(crate) fn handle_reserve_error(err: JosieTryReserveError) -> ! {
+    match err {
+        JosieTryReserveError::CapacityOverflow => panic!("Overflow"),
+        JosieTryReserveError::AllocError { layout } => handle_alloc_error(layout),
+    }
+}
+
+///Turns any RangeBounds into a concrete range of indices, panicking if it is backwards or runs
+///past len
+pub(crate) fn range_from_bounds<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start
+            .checked_add(1)
+            .unwrap_or_else(|| panic!("Range start overflowed usize")),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end
+            .checked_add(1)
+            .unwrap_or_else(|| panic!("Range end overflowed usize")),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    if start > end {
+        panic!("Range start {start} is greater than range end {end}")
+    }
+    if end > len {
+        panic!("Range end {end} is out of range for JosieVec of length {len}")
+    }
+    start..end
+}
+
+// ===============================
+// POINTER HELPERS
+// -------------------------------
+// Zero sized types never take up memory, so a pointer walk over them never moves. These helpers
+// bump the address by one byte per element instead so the distance between two pointers still
+// counts elements, and every read, write or drop of a zero sized type goes through the aligned
+// dangling pointer.
+// -------------------------------
+
+///Returns true if T takes up no memory
+#[inline(always)]
+pub(crate) const fn is_zst<T>() -> bool {
+    size_of::<T>() == 0
+}
+
+///Advances ptr by count elements, or by count bytes for zero sized types
+#[inline(always)]
+pub(crate) unsafe fn ptr_add<T>(ptr: *mut T, count: usize) -> *mut T {
+    if is_zst::<T>() {
+        ptr.wrapping_byte_add(count)
+    } else {
+        unsafe { ptr.add(count) }
+    }
+}
+
+///Moves ptr back by count elements, or by count bytes for zero sized types
+#[inline(always)]
+pub(crate) unsafe fn ptr_sub<T>(ptr: *mut T, count: usize) -> *mut T {
+    if is_zst::<T>() {
+        ptr.wrapping_byte_sub(count)
+    } else {
+        unsafe { ptr.sub(count) }
+    }
+}
+
+///Number of elements between start and end. end must not be before start
+#[inline(always)]
+pub(crate) unsafe fn ptr_distance<T>(start: *const T, end: *const T) -> usize {
+    if is_zst::<T>() {
+        end.addr() - start.addr()
+    } else {
+        unsafe { end.offset_from_unsigned(start) }
+    }
+}
+
+///Pointer an element actually lives at. Zero sized types are always read and written through the
+///dangling pointer so that alignment is upheld no matter how far the walk has bumped ptr
+#[inline(always)]
+pub(crate) fn element_ptr<T>(ptr: *mut T) -> *mut T {
+    if is_zst::<T>() {
+        NonNull::dangling().as_ptr()
+    } else {
+        ptr
+    }
+}
+
+///Writes value to ptr and bumps ptr forward one element
+#[inline(always)]
+pub(crate) unsafe fn write_bump<T>(ptr: &mut *mut T, value: T) {
+    unsafe {
+        element_ptr(*ptr).write(value);
+        *ptr = ptr_add(*ptr, 1);
+    }
+}
+
+///Reads the element at ptr and bumps ptr forward one element
+#[inline(always)]
+pub(crate) unsafe fn read_bump<T>(ptr: &mut *mut T) -> T {
+    unsafe {
+        let out = element_ptr(*ptr).read();
+        *ptr = ptr_add(*ptr, 1);
+        out
+    }
+}
+
+///Moves ptr back one element and reads the element it now points at
+#[inline(always)]
+pub(crate) unsafe fn read_back<T>(ptr: &mut *mut T) -> T {
+    unsafe {
+        *ptr = ptr_sub(*ptr, 1);
+        element_ptr(*ptr).read()
+    }
+}
+
+///Drops len elements in place starting at ptr
+#[inline(always)]
+pub(crate) unsafe fn drop_range<T>(ptr: *mut T, len: usize) {
+    unsafe { drop_in_place(slice_from_raw_parts_mut(element_ptr(ptr), len)) }
+}
+
+impl<T, A: JosieAllocator, G: GrowthPolicy> Deref for JosieVec<T, A, G> {
     type Target = [T];
     fn deref(&self) -> &Self::Target {
         self.as_slice()
     }
 }
 
-impl<T> DerefMut for JosieVec<T> {
+impl<T, A: JosieAllocator, G: GrowthPolicy> DerefMut for JosieVec<T, A, G> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.as_mut_slice()
     }
 }
 
-impl<T> Clone for JosieVec<T>
+impl<T, A, G> Clone for JosieVec<T, A, G>
 where
     T: Clone,
+    A: JosieAllocator + Clone,
+    G: GrowthPolicy + Clone,
 {
     fn clone(&self) -> Self {
-        //creates new josievec with capacity equal to self
-        let mut temp: JosieVec<T> = JosieVec::with_capacity(self.buf.cap);
+        //creates new josievec with capacity equal to self, allocated from a clone of the same allocator
+        let mut temp = Self::with_policy_in(self.policy.clone(), self.buf.alloc.clone());
+        unsafe { temp.realloc_internal(self.buf.cap) }
         //iterates through the josievec and maps the output to a clone
-        for element in self.iter().map(|elem| elem.clone()) {
+        for element in self.iter().cloned() {
             //pushes each cloned element to the josievec. unsafe function call because guarenteed to push only exact number of elements inside josievec
             unsafe { temp.push_internal(element) }
         }
@@ -338,35 +750,27 @@ where
     }
 }
 
-impl<T> Default for JosieVec<T> {
+impl<T, A: JosieAllocator + Default, G: GrowthPolicy + Default> Default for JosieVec<T, A, G> {
     fn default() -> Self {
-        Self {
-            buf: RawJosieVec {
-                ptr: NonNull::dangling(),
-                cap: 0,
-            },
-            len: 0,
-        }
+        Self::with_policy_in(G::default(), A::default())
     }
 }
 
-impl<T> Drop for RawJosieVec<T> {
+impl<T, A: JosieAllocator> Drop for RawJosieVec<T, A> {
     fn drop(&mut self) {
         unsafe {
             if self.cap != 0 {
-                dealloc(
-                    self.ptr.as_ptr() as *mut u8,
-                    Layout::array::<T>(self.cap).expect("Overflow"),
-                );
+                self.alloc
+                    .deallocate(self.ptr.cast(), self.current_layout());
             }
         }
     }
 }
 
-impl<T> Drop for JosieVec<T> {
+impl<T, A: JosieAllocator, G: GrowthPolicy> Drop for JosieVec<T, A, G> {
     fn drop(&mut self) {
         unsafe {
-            drop_in_place(from_raw_parts_mut(self.buf.ptr.as_ptr(), self.len));
+            drop_in_place(slice_from_raw_parts_mut(self.buf.ptr.as_ptr(), self.len));
         }
     }
 }
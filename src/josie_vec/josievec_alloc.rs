@@ -0,0 +1,105 @@
+// ===============================
+// JOSIEVEC ALLOCATOR
+// -------------------------------
+// Stable stand in for the unstable std Allocator trait. Lets JosieVec live in arenas, pools
+// or counting allocators instead of always going through the global allocator
+// -------------------------------
+
+use std::{
+    alloc::{Layout, alloc, dealloc, realloc},
+    ptr::{NonNull, copy_nonoverlapping},
+};
+
+///Allocator used by JosieVec and RawJosieVec for every allocation they make.
+///
+/// # Safety
+///Implementors must hand back pointers to memory that is valid for reads and writes of the
+///requested layout until it is passed back to deallocate, and must never hand out the same
+///memory twice while it is still live. Memory allocated by one value must be deallocatable by any
+///clone of it.
+pub unsafe trait JosieAllocator {
+    ///Allocates memory for the layout. Returns None if the allocation fails. layout always has a
+    ///nonzero size when called by JosieVec
+    fn allocate(&self, layout: Layout) -> Option<NonNull<u8>>;
+
+    ///Deallocates memory previously handed out by allocate or reallocate
+    ///
+    /// # Safety
+    ///ptr must have been allocated by this allocator with exactly this layout
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout);
+
+    ///Resizes an existing allocation. On success the contents up to the smaller of the two sizes
+    ///are preserved and the old pointer must not be used again. On failure returns None and the
+    ///old allocation is left untouched. Default implementation allocates, copies and deallocates
+    ///
+    /// # Safety
+    ///ptr must have been allocated by this allocator with old_layout, and new_layout must have the
+    ///same alignment as old_layout and a nonzero size
+    unsafe fn reallocate(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Option<NonNull<u8>> {
+        let new_ptr = self.allocate(new_layout)?;
+        unsafe {
+            //copies whichever is smaller, the old data or the new buffer
+            copy_nonoverlapping(
+                ptr.as_ptr(),
+                new_ptr.as_ptr(),
+                old_layout.size().min(new_layout.size()),
+            );
+            self.deallocate(ptr, old_layout);
+        }
+        Some(new_ptr)
+    }
+}
+
+///The default allocator for JosieVec, forwards to the global allocator registered for the program
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Global;
+
+unsafe impl JosieAllocator for Global {
+    #[inline(always)]
+    fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+        NonNull::new(unsafe { alloc(layout) })
+    }
+
+    #[inline(always)]
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { dealloc(ptr.as_ptr(), layout) }
+    }
+
+    #[inline(always)]
+    unsafe fn reallocate(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Option<NonNull<u8>> {
+        NonNull::new(unsafe { realloc(ptr.as_ptr(), old_layout, new_layout.size()) })
+    }
+}
+
+//lets a single allocator be shared between many JosieVecs by reference
+unsafe impl<A: JosieAllocator + ?Sized> JosieAllocator for &A {
+    #[inline(always)]
+    fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+        (**self).allocate(layout)
+    }
+
+    #[inline(always)]
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { (**self).deallocate(ptr, layout) }
+    }
+
+    #[inline(always)]
+    unsafe fn reallocate(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Option<NonNull<u8>> {
+        unsafe { (**self).reallocate(ptr, old_layout, new_layout) }
+    }
+}
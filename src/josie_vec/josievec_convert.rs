@@ -0,0 +1,137 @@
+// ===============================
+// RAW PARTS AND STD CONVERSIONS
+// -------------------------------
+// Lets a josievec buffer be handed to and taken from Vec and Box<[T]> without copying. The global
+// allocator lays out its buffers exactly like Vec does, an array of cap T, so ownership of the
+// pointer can move straight across
+// -------------------------------
+
+use std::{
+    mem::ManuallyDrop,
+    ptr::{copy_nonoverlapping, NonNull},
+    slice::from_raw_parts_mut,
+};
+
+use super::{
+    is_zst,
+    josievec_alloc::{Global, JosieAllocator},
+    josievec_growth::GrowthPolicy,
+    JosieVec,
+};
+
+impl<T> JosieVec<T>{
+    ///Creates a JosieVec directly from a pointer, length and capacity, taking ownership of the allocation.
+    ///
+    /// # Safety
+    ///If cap is nonzero and T is not zero sized, ptr must have been allocated by the global allocator with the
+    ///layout of an array of cap T, the same layout Vec uses. If cap is zero or T is zero sized, ptr must be
+    ///non null and aligned. The first len elements must be initialized and len must not be greater than cap.
+    #[inline(always)]
+    pub unsafe fn from_raw_parts(ptr:*mut T, len:usize, cap:usize) -> Self{
+        unsafe{Self::from_raw_parts_in(ptr, len, cap, Global)}
+    }
+}
+
+impl<T, A: JosieAllocator> JosieVec<T, A>{
+    ///Creates a JosieVec directly from a pointer, length, capacity and the allocator that owns the pointer.
+    ///
+    /// # Safety
+    ///Same as from_raw_parts, except that ptr must have been allocated by alloc or an allocator it was cloned from.
+    pub unsafe fn from_raw_parts_in(ptr:*mut T, len:usize, cap:usize, alloc:A) -> Self{
+        let mut temp = Self::new_in(alloc);
+        temp.buf.ptr = unsafe{NonNull::new_unchecked(ptr)};
+        //zero sized types never own an allocation so their stored capacity stays zero, which stops the buffer
+        //from ever deallocating
+        temp.buf.cap = if is_zst::<T>(){0} else{cap};
+        temp.len = len;
+        temp
+    }
+}
+
+impl<T, G: GrowthPolicy> JosieVec<T, Global, G>{
+    ///Decomposes the JosieVec into its pointer, length and capacity. The caller becomes responsible for the
+    ///allocation, usually by passing them to JosieVec::from_raw_parts or Vec::from_raw_parts
+    #[inline(always)]
+    pub fn into_raw_parts(self) -> (*mut T, usize, usize){
+        let (ptr, len, cap, _) = self.into_raw_parts_with_alloc();
+        (ptr, len, cap)
+    }
+}
+
+impl<T, A: JosieAllocator, G: GrowthPolicy> JosieVec<T, A, G>{
+    ///Decomposes the JosieVec into its pointer, length, capacity and allocator. The growth policy is dropped
+    pub fn into_raw_parts_with_alloc(self) -> (*mut T, usize, usize, A){
+        let mut josievec = ManuallyDrop::new(self);
+        let ptr = josievec.as_mut_ptr();
+        let (len, cap) = (josievec.len, josievec.capacity());
+        unsafe{
+            //moves the allocator and policy out, the josievec itself is never dropped so neither is read twice
+            let alloc = (&raw const josievec.buf.alloc).read();
+            drop((&raw const josievec.policy).read());
+            (ptr, len, cap, alloc)
+        }
+    }
+
+    ///Consumes the JosieVec and leaks its allocation, returning a mutable slice of the elements that lives as long
+    ///as the allocator. The memory and the allocator are never freed
+    pub fn leak<'a>(self) -> &'a mut [T]
+    where A: 'a{
+        let mut josievec = ManuallyDrop::new(self);
+        unsafe{from_raw_parts_mut(josievec.as_mut_ptr(), josievec.len)}
+    }
+}
+
+//moves the buffer of a Vec into a JosieVec without copying
+impl<T> From<Vec<T>> for JosieVec<T>{
+    fn from(vec:Vec<T>) -> Self{
+        let mut vec = ManuallyDrop::new(vec);
+        unsafe{Self::from_raw_parts(vec.as_mut_ptr(), vec.len(), vec.capacity())}
+    }
+}
+
+//moves the buffer of a JosieVec into a Vec without copying
+impl<T, G: GrowthPolicy> From<JosieVec<T, Global, G>> for Vec<T>{
+    fn from(josievec:JosieVec<T, Global, G>) -> Self{
+        let (ptr, len, cap) = josievec.into_raw_parts();
+        unsafe{Vec::from_raw_parts(ptr, len, cap)}
+    }
+}
+
+//a boxed slice is an allocation whose capacity is exactly its length
+impl<T> From<Box<[T]>> for JosieVec<T>{
+    fn from(boxed:Box<[T]>) -> Self{
+        let len = boxed.len();
+        let ptr = Box::into_raw(boxed).cast::<T>();
+        unsafe{Self::from_raw_parts(ptr, len, len)}
+    }
+}
+
+//shrinks the allocation to the length then hands it to Box
+impl<T, G: GrowthPolicy> From<JosieVec<T, Global, G>> for Box<[T]>{
+    fn from(josievec:JosieVec<T, Global, G>) -> Self{
+        josievec.into_boxed_slice()
+    }
+}
+
+//moves the elements of an array into a fresh allocation with a single memcpy
+impl<T, const N:usize> From<[T; N]> for JosieVec<T>{
+    fn from(array:[T; N]) -> Self{
+        let mut temp = Self::with_capacity(N);
+        //the array is never dropped, its elements are owned by the josievec after the copy
+        let array = ManuallyDrop::new(array);
+        unsafe{
+            copy_nonoverlapping(array.as_ptr(), temp.as_mut_ptr(), N);
+            temp.set_len(N);
+        }
+        temp
+    }
+}
+
+//clones every element of the slice into a fresh allocation
+impl<T: Clone> From<&[T]> for JosieVec<T>{
+    fn from(slice:&[T]) -> Self{
+        let mut temp = Self::with_capacity(slice.len());
+        temp.extend_from_slice(slice);
+        temp
+    }
+}
@@ -1,37 +1,122 @@
-use std::{ops::Range, ptr::{copy, drop_in_place}, slice::from_raw_parts_mut};
-
-use crate::josie_vec::JosieVec;
-///Iterator for josievec drain, 
-pub struct JosieVecDrain<'a, T>{
-    //mutable reference to the josievec that is being drained
-    pub(crate) josievec:&'a mut JosieVec<T>,
-    //the pointer to the first element of the josievec being drained
-    pub(crate) start_ptr:*mut T,
-    //the actual pointer that is read from
+// ===============================
+// DRAIN IMPLEMENTATION
+// -------------------------------
+// Removes a range of elements from the josievec and hands them out by value. The josievec length is
+// cut down to the start of the range while draining, so leaking the drain only leaks elements and
+// never exposes moved out memory. The tail after the range is moved back into place on drop.
+// -------------------------------
+
+use std::{
+    iter::FusedIterator,
+    mem::ManuallyDrop,
+    ops::RangeBounds,
+    ptr::copy,
+    slice::from_raw_parts,
+};
+
+use crate::josie_vec::{
+    drop_range, element_ptr,
+    josievec_alloc::{Global, JosieAllocator},
+    josievec_growth::{Doubling, GrowthPolicy},
+    ptr_add, ptr_distance, ptr_sub, range_from_bounds, read_back, read_bump, JosieVec,
+};
+
+///Iterator for josievec drain,
+pub struct JosieVecDrain<'a, T, A: JosieAllocator = Global, G: GrowthPolicy = Doubling>{
+    //mutable reference to the josievec that is being drained, its length is set to the start of the range
+    pub(crate) josievec:&'a mut JosieVec<T, A, G>,
+    //the front pointer that is read from
     pub(crate) ptr:*mut T,
-    //the end pointer, points to the last pointer in the 
+    //the end pointer, points one past the last element that hasnt been yielded
     pub(crate) end_ptr:*mut T,
+    //index of the first element after the drained range
+    pub(crate) tail_start:usize,
+    //number of elements after the drained range
+    pub(crate) tail_len:usize,
 }
 
-impl<'a, T> Drop for JosieVecDrain<'a, T>{
+//raii guard used while dropping the drain, moves the tail back into place even if dropping one of the
+//remaining elements panics
+struct JosieVecDrainTailGuard<'r, 'a, T, A: JosieAllocator, G: GrowthPolicy>{
+    drain:&'r mut JosieVecDrain<'a, T, A, G>,
+}
+
+impl<'r, 'a, T, A: JosieAllocator, G: GrowthPolicy> Drop for JosieVecDrainTailGuard<'r, 'a, T, A, G>{
     fn drop(&mut self){
+        let len = self.drain.josievec.len;
+        unsafe{self.drain.move_tail(len)}
+        //the tail is in place now, so finishing again moves nothing
+        self.drain.tail_len = 0;
+    }
+}
+
+impl<'a, T, A: JosieAllocator, G: GrowthPolicy> JosieVecDrain<'a, T, A, G>{
+    ///Returns the elements that havent been yielded yet as a slice
+    pub fn as_slice(&self) -> &[T]{
+        unsafe{from_raw_parts(element_ptr(self.ptr), ptr_distance(self.ptr, self.end_ptr))}
+    }
+
+    ///Returns a reference to the allocator of the josievec being drained
+    #[inline(always)]
+    pub fn allocator(&self) -> &A{
+        self.josievec.allocator()
+    }
+
+    ///Stops draining and keeps every element that hasnt been yielded yet in the josievec, in their original order
+    pub fn keep_rest(self){
+        //the drain is never dropped so its remaining elements arent dropped
+        let mut this = ManuallyDrop::new(self);
+        unsafe{
+            let base = this.josievec.buf.ptr.as_ptr();
+            let start = this.josievec.len;
+            let remaining = ptr_distance(this.ptr, this.end_ptr);
+            //index of the first element that hasnt been yielded
+            let unyielded = ptr_distance(base, this.ptr);
+            //moves the unyielded elements down to the end of the josievec
+            copy(base.add(unyielded), base.add(start), remaining);
+            //then moves the tail in behind them
+            this.move_tail(start + remaining);
+        }
+        //the yielded elements are gone for good so the growth policy can hand back memory
+        this.josievec.apply_shrink_policy();
+    }
+
+    //drops the elements that havent been yielded and moves the tail back into place. does nothing the second
+    //time, so a drain wrapping this one can finish it early and then look at the josievec
+    pub(crate) fn finish(&mut self){
+        let ptr = self.ptr;
+        let remaining = unsafe{ptr_distance(self.ptr, self.end_ptr)};
+        //marks everything as yielded first so nothing is dropped twice if a drop panics
+        self.ptr = self.end_ptr;
+        //arms the guard that moves the tail back once the remaining elements are dropped
+        let _guard = JosieVecDrainTailGuard{drain:self};
         unsafe{
             //drops in place any elements owned by the iterator but not iterated through, allows for safe unwind deallocating any
             //items that would otherwise not be deallocated. if it finishes completely without panicing this does nothing
-            drop_in_place(from_raw_parts_mut(self.ptr, self.end_ptr.offset_from_unsigned(self.ptr)));
-            //elems drained is the number of elements drained from the vector
-            let elems_drained = self.ptr.offset_from_unsigned(self.start_ptr);
-            //the number of elements present after the porton of the josievec that was drained
-            let remaining = self.josievec.len  - self.end_ptr.offset_from_unsigned(self.start_ptr) -1;
-            //subtracts erlements drained from length
-            self.josievec.len -= elems_drained;
+            drop_range(ptr, remaining);
+        }
+    }
+
+    //moves the tail so it starts at index dst and sets the josievec length to include it
+    unsafe fn move_tail(&mut self, dst:usize){
+        unsafe{
+            let base = self.josievec.buf.ptr.as_ptr();
             //copies the elements to the start_ptr
-            copy(self.ptr, self.start_ptr, remaining);
+            copy(base.add(self.tail_start), base.add(dst), self.tail_len);
         }
+        self.josievec.len = dst + self.tail_len;
     }
 }
 
-impl<'a, T> Iterator for JosieVecDrain<'a, T>{
+impl<'a, T, A: JosieAllocator, G: GrowthPolicy> Drop for JosieVecDrain<'a, T, A, G>{
+    fn drop(&mut self){
+        self.finish();
+        //the tail is back in place so the growth policy can hand back memory
+        self.josievec.apply_shrink_policy();
+    }
+}
+
+impl<'a, T, A: JosieAllocator, G: GrowthPolicy> Iterator for JosieVecDrain<'a, T, A, G>{
     type Item = T;
 
     fn next(&mut self) -> Option<T>{
@@ -39,33 +124,69 @@ impl<'a, T> Iterator for JosieVecDrain<'a, T>{
             return None;
         }
         unsafe{
-            let out = self.ptr.read();
-            self.ptr = self.ptr.add(1);
-            Some(out)
+            Some(read_bump(&mut self.ptr))
         }
-        
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let size = unsafe{self.end_ptr.offset_from_unsigned(self.start_ptr)};
+        let size = unsafe{ptr_distance(self.ptr, self.end_ptr)};
         (size, Some(size))
     }
-    
+
+    fn nth(&mut self, n:usize) -> Option<T>{
+        let skip = n.min(self.len());
+        let ptr = self.ptr;
+        unsafe{
+            //moves past the skipped elements before dropping them so a panicking drop cant drop them twice
+            self.ptr = ptr_add(ptr, skip);
+            drop_range(ptr, skip);
+        }
+        self.next()
+    }
 }
 
-impl<T> JosieVec<T>{
-    pub fn drain(&mut self, range:Range<usize>)-> JosieVecDrain<T>{
+impl<'a, T, A: JosieAllocator, G: GrowthPolicy> DoubleEndedIterator for JosieVecDrain<'a, T, A, G>{
+    fn next_back(&mut self) -> Option<T>{
+        if self.ptr == self.end_ptr{
+            return None;
+        }
         unsafe{
-            if range.start >= self.len || range.end >= self.len{
-                panic!("Tried to drain out of range of josievec");
-            }
-            let ptr = self.buf.ptr.as_ptr().add(range.start);
+            Some(read_back(&mut self.end_ptr))
+        }
+    }
+
+    fn nth_back(&mut self, n:usize) -> Option<T>{
+        let skip = n.min(self.len());
+        unsafe{
+            self.end_ptr = ptr_sub(self.end_ptr, skip);
+            drop_range(self.end_ptr, skip);
+        }
+        self.next_back()
+    }
+}
+
+impl<'a, T, A: JosieAllocator, G: GrowthPolicy> ExactSizeIterator for JosieVecDrain<'a, T, A, G>{}
+impl<'a, T, A: JosieAllocator, G: GrowthPolicy> FusedIterator for JosieVecDrain<'a, T, A, G>{}
+
+impl<T, A: JosieAllocator, G: GrowthPolicy> JosieVec<T, A, G>{
+    ///Removes the elements in range from the josievec and returns them as an iterator. Accepts any range syntax,
+    ///including .. and a..=b. Whatever the iterator doesnt yield is dropped when it is, and the elements after the
+    ///range are moved back to close the gap.
+    pub fn drain<R>(&mut self, range:R) -> JosieVecDrain<'_, T, A, G>
+    where R: RangeBounds<usize>{
+        let range = range_from_bounds(range, self.len);
+        let len = self.len;
+        //cuts the josievec down to the start of the range so leaking the drain cant expose drained elements
+        self.len = range.start;
+        unsafe{
+            let ptr = ptr_add(self.buf.ptr.as_ptr(), range.start);
             JosieVecDrain{
                 josievec:self,
-                start_ptr:ptr,
-                ptr:ptr,
-                end_ptr:ptr.add(range.end),
+                ptr,
+                end_ptr:ptr_add(ptr, range.end - range.start),
+                tail_start:range.end,
+                tail_len:len - range.end,
             }
         }
     }
-}
\ No newline at end of file
+}
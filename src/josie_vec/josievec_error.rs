@@ -0,0 +1,40 @@
+// ===============================
+// TRY RESERVE ERROR
+// -------------------------------
+// Error returned by the fallible allocation api instead of panicking or aborting
+// -------------------------------
+
+use std::{alloc::Layout, error::Error, fmt};
+
+///Error returned by the try_ family of methods on JosieVec when an allocation can not be made.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JosieTryReserveError {
+    ///The requested capacity overflowed usize or the byte size of the allocation would exceed isize::MAX
+    CapacityOverflow,
+    ///The allocator returned a null pointer for the requested layout
+    AllocError {
+        ///Layout of the allocation that failed
+        layout: Layout,
+    },
+}
+
+impl fmt::Display for JosieTryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JosieTryReserveError::CapacityOverflow => {
+                write!(
+                    f,
+                    "memory allocation failed because the computed capacity exceeded the collection's maximum"
+                )
+            }
+            JosieTryReserveError::AllocError { layout } => write!(
+                f,
+                "memory allocation of {} bytes with alignment {} failed",
+                layout.size(),
+                layout.align()
+            ),
+        }
+    }
+}
+
+impl Error for JosieTryReserveError {}
@@ -8,34 +8,36 @@
 
 use std::{
     mem::ManuallyDrop,
+    ops::RangeBounds,
+    ptr::copy_nonoverlapping,
 };
 
-use super::JosieVec;
+use super::{josievec_alloc::JosieAllocator, josievec_growth::GrowthPolicy, ptr_add, ptr_distance, range_from_bounds, write_bump, JosieVec};
 
 //raii drop guard for josievec, on drop drops in place any elements initialized before 
-struct JosieVecGuard<'a, T>{
+pub(crate) struct JosieVecGuard<'a, T, A: JosieAllocator, G: GrowthPolicy>{
     //the length of the josievec at start of push
-    start_len:usize,
+    pub(crate) start_len:usize,
     //mutable reference to the josievec
-    josievec:&'a mut JosieVec<T>,
+    pub(crate) josievec:&'a mut JosieVec<T, A, G>,
     //start pointer and end pointer for the data in the struct
-    start_ptr:*mut T,
-    ptr:*mut T,
+    pub(crate) start_ptr:*mut T,
+    pub(crate) ptr:*mut T,
 }
 
-impl<'a, T> JosieVecGuard<'a, T>{
+impl<'a, T, A: JosieAllocator, G: GrowthPolicy> JosieVecGuard<'a, T, A, G>{
     //arms the josievec guard via saving all relevent fields to the struct
-    fn arm(josievec:&'a mut JosieVec<T>) -> Self{
+    pub(crate) fn arm(josievec:&'a mut JosieVec<T, A, G>) -> Self{
         //constructs pointer from the josievecs pointer offset by the current length
         let ptr = unsafe{
-            josievec.buf.ptr.as_ptr().add(josievec.len)
+            ptr_add(josievec.buf.ptr.as_ptr(), josievec.len)
         };
 
         Self{
             start_len:josievec.len,
             start_ptr:ptr,
-            ptr:ptr,
-            josievec:josievec,
+            ptr,
+            josievec,
 
         }
     }
@@ -45,13 +47,26 @@ impl<'a, T> JosieVecGuard<'a, T>{
         //will convert self to manually drop so that drop code doesnt run on disarm.
         let _ = ManuallyDrop::new(self);
     }
+
+    //commits the elements written so far to the josievec length and rearms the guard at the new end. used before
+    //anything that can reallocate the buffer
+    pub(crate) fn rearm(&mut self){
+        self.josievec.len = self.start_len + unsafe{
+            ptr_distance(self.start_ptr, self.ptr)
+        };
+        self.start_len = self.josievec.len;
+        self.start_ptr = unsafe{
+            ptr_add(self.josievec.buf.ptr.as_ptr(), self.josievec.len)
+        };
+        self.ptr = self.start_ptr;
+    }
 }
 
-impl<'a, T> Drop for JosieVecGuard<'a, T>{
+impl<'a, T, A: JosieAllocator, G: GrowthPolicy> Drop for JosieVecGuard<'a, T, A, G>{
     fn drop(&mut self) {
         //the only time this code runs is if the push never finished because of a panic.
         self.josievec.len = self.start_len + unsafe{
-            self.ptr.offset_from_unsigned(self.start_ptr)
+            ptr_distance(self.start_ptr, self.ptr)
         };
     }
 }
@@ -64,19 +79,18 @@ macro_rules! push_iter {
         unsafe{
             //pushes contents of iterator to josievec
             for element in $iterator.take({$bound}){
-                guard.ptr.write(element);
-                guard.ptr = guard.ptr.add(1);
+                //writes to pointer and increments it by one
+                write_bump(&mut guard.ptr, element);
             }
         }
-        //disarms josievec guard
-        guard.commit();
-        //sets length to final value
-        $josievec.len += $bound;
+        //drops the guard which sets length to the number of elements actually written, an iterator
+        //can hand back fewer elements than its upper bound promised
+        drop(guard);
     };
     (unbounded $josievec:expr, $iterator:expr)=>{
         'outer: loop{
             //creates variable for the number of elements by subtracting current length from bounds
-            let elems = $josievec.buf.cap - $josievec.len;
+            let elems = $josievec.buf.capacity() - $josievec.len;
             //arms josievec guard
             let mut guard = JosieVecGuard::arm($josievec);
             unsafe{
@@ -85,17 +99,15 @@ macro_rules! push_iter {
                     //if iterator returns some then write it to the pointer
                     if let Some(element) = $iterator.next(){
                         //if i==5{break 'outer}
-                        //writes to pointer
-                        guard.ptr.write(element);
-                        //increments pointer by one
-                        guard.ptr = guard.ptr.add(1);
+                        //writes to pointer and increments pointer by one
+                        write_bump(&mut guard.ptr, element);
                     }
                     //else set josievec len to equal to the bounds - the elements + the current iteration, setting length properly.
                     else{
                         //commits changes to josievec
                         guard.commit();
                         //sets length properly given data
-                        $josievec.len = ($josievec.buf.cap - elems + i);
+                        $josievec.len = ($josievec.buf.capacity() - elems + i);
                         //breaks outer loop
                         break 'outer;
                     }
@@ -103,7 +115,7 @@ macro_rules! push_iter {
                 //comitts changes to the josievec
                 guard.commit();
                 //sets the length to be equal to current cap
-                $josievec.len = $josievec.buf.cap;
+                $josievec.len = $josievec.buf.capacity();
                 //grows the vector
                 $josievec.grow_amortized();
             }
@@ -111,12 +123,14 @@ macro_rules! push_iter {
     }
 }
 
+///How much capacity bulk_extend_guarded and writer reserve before writing. Exact reserves room for that many
+///elements, Ammortized grows the josievec if it is full and hands out whatever capacity is left
 pub enum ExtendType{
     Exact(usize),
     Ammortized,
 }
 
-impl<T> JosieVec<T>{
+impl<T, A: JosieAllocator, G: GrowthPolicy> JosieVec<T, A, G>{
     ///Bulk extend Guaured is an unsafe function that attempts to eliminate as many invariants from bulk unsafe writes as humanly possible
     ///while remaining a fast pointer walk. 
     ///Usage:Certain Invariants must be upheld for this function to be used safely. Number one, you must write valid T to the Josievec.
@@ -132,35 +146,36 @@ impl<T> JosieVec<T>{
     ///offset of the write pointer from the start pointer held inside JosieVecGuard, so as long as you account for previously mentioned invariants
     ///you dont need to worry about setting the length manually. if code inside the loop panics JosieVecGuard will also make sure the capacity is 
     ///consistent with elements written. For Example on how to implement this, check out fibonacci_test.
+    ///
+    /// # Safety
+    ///The closure must only write valid T between the pointer it is handed and end_ptr, and must only ever
+    ///advance the pointer forwards.
     pub unsafe fn bulk_extend_guarded<F>(&mut self, extend_type:ExtendType, f:F)
     where F: FnOnce(&mut *mut T,*mut T){
-        //creates new variable elems
-        let elems:usize;
-        //matches extend type to either end Exact or ammortized
-        match extend_type{
+        //matches extend type to either end Exact or ammortized, setting elems to the number of elements that can be written
+        let elems = match extend_type{
             ExtendType::Exact(elements)=>{
                 //reserves enough capacity for elems that will be written
                 self.reserve(elements);
                 //sets number of elems to be equal to the number of elements that will be written
-                elems = elements;
-                
+                elements
             },
             ExtendType::Ammortized=>{
                 //if current len is equal to the buffer cap then grow ammortized
-                if self.len == self.buf.cap{
+                if self.len == self.buf.capacity(){
                     self.grow_amortized();
                 }
                 //sets elems to the cap minus current length
-                elems = self.buf.cap - self.len;
+                self.buf.capacity() - self.len
             }
-        }
+        };
         //creates end pointer that is pointing at the max capacity
         let mut guard = JosieVecGuard::arm(self);
         //sets end_ptr to be equal to the pointer location of the max elems you are pushing into the vec
         //the pointer you use to write to
         let ptr = &mut guard.ptr;
         unsafe{
-        let end_ptr = ptr.add(elems);
+        let end_ptr = ptr_add(*ptr, elems);
         //passes in a mutable reference to the raii drop guards end ptr to manipulate along with a pointer pointing to the last valid
         //memory location in the buffer
         f(ptr, end_ptr);
@@ -171,10 +186,54 @@ impl<T> JosieVec<T>{
 
 }
 
+// ===============================
+// BULK SLICE EXTENDS
+// -------------------------------
+// The clone versions write through the raii guard so a panicking clone leaves every element written so
+// far in the josievec. Stable rust cant specialize on Copy, so the memcpy path for slices of Copy types
+// is its own method
+// -------------------------------
+
+impl<T: Clone, A: JosieAllocator, G: GrowthPolicy> JosieVec<T, A, G>{
+    ///Clones every element of slice onto the end of the josievec. For Copy types extend_from_copy_slice does the
+    ///same thing with a single memcpy
+    pub fn extend_from_slice(&mut self, slice:&[T]){
+        push_iter!(bounded self, slice.len(), slice.iter().cloned());
+    }
+
+    ///Clones the elements in range onto the end of the josievec
+    pub fn extend_from_within<R>(&mut self, range:R)
+    where R: RangeBounds<usize>{
+        let range = range_from_bounds(range, self.len);
+        //reserves first so the source pointer stays valid for the whole clone
+        self.reserve(range.len());
+        let src = self.buf.ptr.as_ptr();
+        let mut guard = JosieVecGuard::arm(self);
+        unsafe{
+            for i in range{
+                //the source elements are all below len so they are never written over while cloning
+                write_bump(&mut guard.ptr, (*src.add(i)).clone());
+            }
+        }
+        //guard drops here and sets length to the number of elements cloned
+    }
+}
+
+impl<T: Copy, A: JosieAllocator, G: GrowthPolicy> JosieVec<T, A, G>{
+    ///Copies every element of slice onto the end of the josievec with a single memcpy
+    pub fn extend_from_copy_slice(&mut self, slice:&[T]){
+        self.reserve(slice.len());
+        unsafe{
+            copy_nonoverlapping(slice.as_ptr(), self.buf.ptr.as_ptr().add(self.len), slice.len());
+        }
+        self.len += slice.len();
+    }
+}
+
 //implementation for extend for JosieVec
-impl<T> Extend<T> for JosieVec<T>{
-    fn extend<A>(&mut self, iter: A)
-    where A: IntoIterator<Item = T>{
+impl<T, A: JosieAllocator, G: GrowthPolicy> Extend<T> for JosieVec<T, A, G>{
+    fn extend<I>(&mut self, iter: I)
+    where I: IntoIterator<Item = T>{
         //creates new iterator from iter
         let mut iterator = iter.into_iter();
         //gets possible ranges for iter based off of size_hint
@@ -182,7 +241,7 @@ impl<T> Extend<T> for JosieVec<T>{
         //checks for an upper bound on the iterator
         match iter_ranges{
             //if upper bound for iterator is zero return straight away
-            (_, Some(0)) => return,
+            (_, Some(0)) => (),
             //if there is a lower bound and an upper bound and both are equal allocate enough capacity for the upper bound
             (lower_bound, Some(upper_bound)) if lower_bound == upper_bound =>{
                  push_iter!(bounded self, upper_bound, iterator);},
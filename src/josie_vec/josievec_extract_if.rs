@@ -0,0 +1,103 @@
+// ===============================
+// EXTRACT IF IMPLEMENTATION
+// -------------------------------
+// Lazily removes and yields the elements in a range that match a predicate. Kept elements are moved
+// back over the holes in the same pass, and whatever hasnt been checked yet is moved back on drop,
+// the same way drain puts its tail back
+// -------------------------------
+
+use std::{
+    ops::RangeBounds,
+    ptr::copy,
+};
+
+use super::{
+    josievec_alloc::{Global, JosieAllocator},
+    josievec_growth::{Doubling, GrowthPolicy},
+    range_from_bounds, JosieVec,
+};
+
+///Iterator returned by JosieVec::extract_if, yields every element in the range the predicate returns true for
+pub struct JosieVecExtractIf<'a, T, F, A: JosieAllocator = Global, G: GrowthPolicy = Doubling>
+where F: FnMut(&mut T) -> bool{
+    //mutable reference to the josievec, its length is set to zero while extracting
+    pub(crate) josievec:&'a mut JosieVec<T, A, G>,
+    //index of the next element to check
+    pub(crate) idx:usize,
+    //index one past the last element in the range
+    pub(crate) end:usize,
+    //number of elements removed so far
+    pub(crate) deleted:usize,
+    //length of the josievec before extracting started
+    pub(crate) original_len:usize,
+    //predicate deciding which elements are removed
+    pub(crate) pred:F,
+}
+
+impl<'a, T, F, A: JosieAllocator, G: GrowthPolicy> Iterator for JosieVecExtractIf<'a, T, F, A, G>
+where F: FnMut(&mut T) -> bool{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T>{
+        unsafe{
+            let ptr = self.josievec.buf.ptr.as_ptr();
+            while self.idx < self.end{
+                let current = ptr.add(self.idx);
+                //if the predicate panics idx hasnt moved, so drop keeps the current element
+                let matched = (self.pred)(&mut *current);
+                self.idx += 1;
+                if matched{
+                    self.deleted += 1;
+                    return Some(current.read());
+                }
+                //moves the kept element back over any holes left by removed elements
+                if self.deleted > 0{
+                    copy(current, ptr.add(self.idx - 1 - self.deleted), 1);
+                }
+            }
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>){
+        //anywhere from none to every element left in the range could match
+        (0, Some(self.end - self.idx))
+    }
+}
+
+impl<'a, T, F, A: JosieAllocator, G: GrowthPolicy> Drop for JosieVecExtractIf<'a, T, F, A, G>
+where F: FnMut(&mut T) -> bool{
+    fn drop(&mut self){
+        unsafe{
+            //moves every element that wasnt checked, including anything after the range, back over the holes
+            if self.deleted > 0 && self.idx < self.original_len{
+                let ptr = self.josievec.buf.ptr.as_ptr();
+                copy(ptr.add(self.idx), ptr.add(self.idx - self.deleted), self.original_len - self.idx);
+            }
+        }
+        //sets length to everything that wasnt removed
+        self.josievec.len = self.original_len - self.deleted;
+        self.josievec.apply_shrink_policy();
+    }
+}
+
+impl<T, A: JosieAllocator, G: GrowthPolicy> JosieVec<T, A, G>{
+    ///Returns an iterator that removes and yields every element in range the predicate returns true for, keeping the
+    ///rest in their original order. Elements are only checked as the iterator is advanced, if it is dropped early the
+    ///unchecked elements are kept.
+    pub fn extract_if<R, F>(&mut self, range:R, pred:F) -> JosieVecExtractIf<'_, T, F, A, G>
+    where R: RangeBounds<usize>, F: FnMut(&mut T) -> bool{
+        let range = range_from_bounds(range, self.len);
+        let original_len = self.len;
+        //sets length to zero so leaking the iterator cant expose elements that have been moved out
+        self.len = 0;
+        JosieVecExtractIf{
+            josievec:self,
+            idx:range.start,
+            end:range.end,
+            deleted:0,
+            original_len,
+            pred,
+        }
+    }
+}
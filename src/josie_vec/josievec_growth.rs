@@ -0,0 +1,117 @@
+// ===============================
+// GROWTH POLICIES
+// -------------------------------
+// Decides how much capacity a josievec asks for when it runs out of room, and optionally when to
+// hand memory back after elements are removed. Selected per josievec through its third type
+// parameter, and stored as a value so policies like FixedIncrement can be set at runtime
+// -------------------------------
+
+///Policy deciding how a JosieVec grows and shrinks its capacity.
+pub trait GrowthPolicy {
+    ///Returns the capacity to grow to when the buffer holds cap elements and at least required are
+    ///needed. elem_size is the size in bytes of one element. Returning None reports a capacity
+    ///overflow. Anything less than required is raised to required.
+    fn grow(&self, cap: usize, required: usize, elem_size: usize) -> Option<usize>;
+
+    ///Called after elements are removed. Returns the capacity to shrink to, or None to keep the
+    ///current one. Anything less than len is raised to len. Never shrinks by default.
+    #[inline(always)]
+    fn shrink(&self, _len: usize, _cap: usize, _elem_size: usize) -> Option<usize> {
+        None
+    }
+}
+
+///Doubles the capacity until it fits, starting from 1. The default policy
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Doubling;
+
+impl GrowthPolicy for Doubling {
+    #[inline(always)]
+    fn grow(&self, cap: usize, required: usize, _elem_size: usize) -> Option<usize> {
+        //sets new capacity variable to be equal to current capacity
+        let mut new_capacity = cap;
+        //keep doubling until you get a capacity that is greater than what is required
+        while required > new_capacity {
+            new_capacity = match new_capacity {
+                //new capacity is set to one if size is currently zero
+                0 => 1,
+                //new capacity is doubled if it is anything else
+                _ => new_capacity.checked_mul(2)?,
+            }
+        }
+        Some(new_capacity)
+    }
+}
+
+///Grows the capacity by half again until it fits, trading more reallocations for less unused memory
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OneAndAHalf;
+
+impl GrowthPolicy for OneAndAHalf {
+    #[inline(always)]
+    fn grow(&self, cap: usize, required: usize, _elem_size: usize) -> Option<usize> {
+        let mut new_capacity = cap;
+        while required > new_capacity {
+            //always grows by at least one so small capacities still move
+            new_capacity = new_capacity.checked_add((new_capacity / 2).max(1))?;
+        }
+        Some(new_capacity)
+    }
+}
+
+///Grows the capacity in steps of a fixed number of elements. A step of zero is treated as one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedIncrement(pub usize);
+
+impl GrowthPolicy for FixedIncrement {
+    #[inline(always)]
+    fn grow(&self, cap: usize, required: usize, _elem_size: usize) -> Option<usize> {
+        let step = self.0.max(1);
+        //rounds the missing capacity up to a whole number of steps
+        let steps = (required - cap).div_ceil(step);
+        cap.checked_add(steps.checked_mul(step)?)
+    }
+}
+
+///Doubles like Doubling, then rounds the allocation up to a whole number of pages so no part of
+///the last page goes to waste. The page size is in bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageRounded {
+    pub page_size: usize,
+}
+
+impl Default for PageRounded {
+    fn default() -> Self {
+        Self { page_size: 4096 }
+    }
+}
+
+impl GrowthPolicy for PageRounded {
+    #[inline(always)]
+    fn grow(&self, cap: usize, required: usize, elem_size: usize) -> Option<usize> {
+        let doubled = Doubling.grow(cap, required, elem_size)?;
+        //rounds the size in bytes up to the next page and fits as many elements in as possible
+        let bytes = doubled
+            .checked_mul(elem_size)?
+            .checked_next_multiple_of(self.page_size.max(1))?;
+        Some(bytes / elem_size.max(1))
+    }
+}
+
+///Wraps another policy and adds hysteresis shrinking: once the length drops below a quarter of
+///the capacity the capacity is halved. Growing is left to the wrapped policy. Halving at a
+///quarter instead of a half means a josievec hovering around one size never thrashes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HalveOnQuarter<G: GrowthPolicy = Doubling>(pub G);
+
+impl<G: GrowthPolicy> GrowthPolicy for HalveOnQuarter<G> {
+    #[inline(always)]
+    fn grow(&self, cap: usize, required: usize, elem_size: usize) -> Option<usize> {
+        self.0.grow(cap, required, elem_size)
+    }
+
+    #[inline(always)]
+    fn shrink(&self, len: usize, cap: usize, _elem_size: usize) -> Option<usize> {
+        if len < cap / 4 { Some(cap / 2) } else { None }
+    }
+}
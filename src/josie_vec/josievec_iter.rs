@@ -1,11 +1,26 @@
-use std::{alloc::{dealloc, Layout}, mem::{take, ManuallyDrop}, ptr::{drop_in_place}, slice::from_raw_parts_mut};
+use std::{iter::FusedIterator, mem::{take, ManuallyDrop}, ptr::{copy, read}, slice::{from_raw_parts, from_raw_parts_mut}};
 
-use super::JosieVec;
+use super::{drop_range, element_ptr, josievec_alloc::{Global, JosieAllocator}, josievec_growth::{Doubling, GrowthPolicy}, ptr_add, ptr_distance, ptr_sub, read_back, read_bump, JosieVec, RawJosieVec};
 
 pub struct JosieVecIterRef<'a, T>{
     pub(crate) slice:&'a [T],
 }
 
+//a shared iterator can always be copied, the elements themselves are never cloned
+impl<T> Clone for JosieVecIterRef<'_, T>{
+    fn clone(&self) -> Self{
+        Self{slice:self.slice}
+    }
+}
+
+impl<'a, T> JosieVecIterRef<'a, T>{
+    ///Returns the elements that havent been yielded yet as a slice
+    #[inline(always)]
+    pub const fn as_slice(&self) -> &'a [T]{
+        self.slice
+    }
+}
+
 pub struct JosieVecIterMut<'a, T>{
     pub(crate) slice:&'a mut [T],
 }
@@ -23,11 +38,11 @@ macro_rules! into_iterator {
     };
 }
 
-impl<'a, T> IntoIterator for &'a JosieVec<T> {
+impl<'a, T, A: JosieAllocator, G: GrowthPolicy> IntoIterator for &'a JosieVec<T, A, G> {
     into_iterator!{&'a T,JosieVecIterRef<'a, T>,iter}
 }
 
-impl<'a, T> IntoIterator for &'a mut JosieVec<T> {
+impl<'a, T, A: JosieAllocator, G: GrowthPolicy> IntoIterator for &'a mut JosieVec<T, A, G> {
     into_iterator!{&'a mut  T,JosieVecIterMut<'a, T>,iter_mut}
 }
 
@@ -54,6 +69,23 @@ impl<'a, T> Iterator for JosieVecIterRef<'a, T>{
         let size = self.slice.len();
             (size,Some(size))
     }
+
+    fn nth(&mut self, n:usize) -> Option<Self::Item>{
+        //skips n elements in one step, running off the end leaves the iterator empty
+        self.slice = self.slice.get(n..).unwrap_or_default();
+        self.next()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for JosieVecIterRef<'a, T>{
+    fn next_back(&mut self) -> Option<Self::Item>{
+        output_first_element!(self.slice, self.slice, split_last)
+    }
+
+    fn nth_back(&mut self, n:usize) -> Option<Self::Item>{
+        self.slice = self.slice.get(..self.slice.len().saturating_sub(n)).unwrap_or_default();
+        self.next_back()
+    }
 }
 
 impl<'a, T> Iterator for JosieVecIterMut<'a, T>{
@@ -68,59 +100,200 @@ impl<'a, T> Iterator for JosieVecIterMut<'a, T>{
         let size = self.slice.len();
         (size,Some(size))
     }
+
+    fn nth(&mut self, n:usize) -> Option<Self::Item>{
+        let take = take(&mut self.slice);
+        self.slice = take.get_mut(n..).unwrap_or_default();
+        self.next()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for JosieVecIterMut<'a, T>{
+    fn next_back(&mut self) -> Option<Self::Item>{
+        let take = take(&mut self.slice);
+        output_first_element!(take,self.slice, split_last_mut)
+    }
+
+    fn nth_back(&mut self, n:usize) -> Option<Self::Item>{
+        let take = take(&mut self.slice);
+        let len = take.len().saturating_sub(n);
+        self.slice = take.get_mut(..len).unwrap_or_default();
+        self.next_back()
+    }
+}
+
+impl<'a, T> JosieVecIterMut<'a, T>{
+    ///Returns the elements that havent been yielded yet as a slice
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T]{
+        self.slice
+    }
 }
 
+//every josievec iterator knows exactly how many elements it has left and keeps returning None once it runs out
+impl<T> ExactSizeIterator for JosieVecIterRef<'_, T>{}
+impl<T> FusedIterator for JosieVecIterRef<'_, T>{}
+impl<T> ExactSizeIterator for JosieVecIterMut<'_, T>{}
+impl<T> FusedIterator for JosieVecIterMut<'_, T>{}
 
 
-impl<T> JosieVec<T>{     
+
+impl<T, A: JosieAllocator, G: GrowthPolicy> JosieVec<T, A, G>{     
     #[inline(always)]
-    pub const fn iter(&self) -> JosieVecIterRef<T>{
+    pub const fn iter(&self) -> JosieVecIterRef<'_, T>{
         JosieVecIterRef {slice:self.as_slice()}
     }
     #[inline(always)]
-    pub const fn iter_mut(&mut self) -> JosieVecIterMut<T>{
+    pub const fn iter_mut(&mut self) -> JosieVecIterMut<'_, T>{
         JosieVecIterMut {slice:self.as_mut_slice()}
     }
 }
 
-
-pub struct JosieVecIter<T>{
-    pub(crate) start_ptr:*mut T,
+///Owning iterator returned by JosieVec::into_iter. Owns the whole buffer including its spare capacity, so it can
+///be turned back into a josievec or mapped in place without allocating
+pub struct JosieVecIter<T, A: JosieAllocator = Global, G: GrowthPolicy = Doubling>{
+    //the buffer taken from the josievec, deallocated with the allocator that owns it once the iterator drops
+    pub(crate) buf:RawJosieVec<T, A>,
+    //the growth policy of the josievec, handed back when the iterator is turned back into one
+    pub(crate) policy:G,
     pub(crate) ptr:*mut T,
     pub(crate) end_ptr:*mut T,
 }
 
-impl<T> Drop for JosieVecIter<T>{
+impl<T, A: JosieAllocator, G: GrowthPolicy> JosieVecIter<T, A, G>{
+    ///Returns a reference to the allocator that owns the buffer being iterated
+    #[inline(always)]
+    pub const fn allocator(&self) -> &A{
+        &self.buf.alloc
+    }
+
+    ///Returns the elements that havent been yielded yet as a slice
+    pub fn as_slice(&self) -> &[T]{
+        unsafe{from_raw_parts(element_ptr(self.ptr), ptr_distance(self.ptr, self.end_ptr))}
+    }
+
+    ///Returns the elements that havent been yielded yet as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [T]{
+        unsafe{from_raw_parts_mut(element_ptr(self.ptr), ptr_distance(self.ptr, self.end_ptr))}
+    }
+
+    ///Turns the elements that havent been yielded yet back into a josievec, reusing the buffer, its capacity and
+    ///the growth policy
+    pub fn into_josievec(self) -> JosieVec<T, A, G>{
+        //the iterator is never dropped so the remaining elements and the buffer are only owned once
+        let this = ManuallyDrop::new(self);
+        let len = this.len();
+        unsafe{
+            let buf = read(&this.buf);
+            //moves the remaining elements down to the start of the buffer, does nothing for zero sized types
+            copy(element_ptr(this.ptr), buf.ptr.as_ptr(), len);
+            JosieVec{buf, len, policy:read(&this.policy)}
+        }
+    }
+
+    ///Maps every element that hasnt been yielded yet and collects the results into a josievec. When U has the
+    ///same size and alignment as T each result is written over the element it came from, so the buffer is reused
+    ///without allocating. Otherwise the results are collected into a new buffer from a clone of the allocator. Either
+    ///way the josievec keeps the growth policy.
+    ///Collecting a map adapter can not reuse the buffer on stable since FromIterator can not be specialized, this
+    ///is the explicit version of into_iter().map(f).collect()
+    pub fn map_in_place<U, F>(self, mut f:F) -> JosieVec<U, A, G>
+    where F: FnMut(T) -> U, A: Clone, G: Clone{
+        if size_of::<U>() != size_of::<T>() || align_of::<U>() != align_of::<T>(){
+            let mut temp = JosieVec::with_policy_in(self.policy.clone(), self.buf.alloc.clone());
+            temp.reserve_exact(self.len());
+            temp.extend(self.map(f));
+            return temp;
+        }
+        let this = ManuallyDrop::new(self);
+        let len = this.len();
+        unsafe{
+            //owns the buffer and policy from here on, the buffer is deallocated as a buffer of T if f panics
+            let buf = read(&this.buf);
+            let policy = read(&this.policy);
+            let dst = buf.ptr.as_ptr().cast::<U>();
+            let mut guard = InPlaceMapGuard{dst, written:0, src:this.ptr, end:this.end_ptr};
+            while guard.src != guard.end{
+                let element = read_bump(&mut guard.src);
+                //the write position never passes the read position so no unread element is overwritten
+                element_ptr(ptr_add(dst, guard.written)).write(f(element));
+                guard.written += 1;
+            }
+            //every element has been mapped so the guard has nothing left to clean up
+            let _ = ManuallyDrop::new(guard);
+            //U has the same layout as T so the allocation is a valid buffer of cap U
+            let buf = ManuallyDrop::new(buf);
+            JosieVec{
+                buf:RawJosieVec{ptr:buf.ptr.cast(), cap:buf.cap, alloc:read(&buf.alloc)},
+                len,
+                policy,
+            }
+        }
+    }
+}
+
+//raii guard for map_in_place, on panic drops the results written so far and the elements not read yet
+struct InPlaceMapGuard<T, U>{
+    //start of the buffer and number of mapped elements written to it
+    dst:*mut U,
+    written:usize,
+    //next element to be read and the end of the elements
+    src:*mut T,
+    end:*mut T,
+}
+
+impl<T, U> Drop for InPlaceMapGuard<T, U>{
+    fn drop(&mut self){
+        unsafe{
+            drop_range(self.dst, self.written);
+            drop_range(self.src, ptr_distance(self.src, self.end));
+        }
+    }
+}
+
+//clones the elements that havent been yielded yet into a new buffer from a clone of the allocator
+impl<T: Clone, A: JosieAllocator + Clone, G: GrowthPolicy + Clone> Clone for JosieVecIter<T, A, G>{
+    fn clone(&self) -> Self{
+        let mut temp = JosieVec::with_policy_in(self.policy.clone(), self.buf.alloc.clone());
+        temp.reserve_exact(self.len());
+        temp.extend_from_slice(self.as_slice());
+        temp.into_iter()
+    }
+}
+
+impl<T, A: JosieAllocator, G: GrowthPolicy> Drop for JosieVecIter<T, A, G>{
     fn drop(&mut self){
         unsafe{
             //drops in place any elements owned by the iterator but not iterated through, allows for safe unwind deallocating any
             //items that would otherwise not be deallocated. if it finishes completely without panicing this does nothing
-            drop_in_place(from_raw_parts_mut(self.ptr, self.end_ptr.offset_from_unsigned(self.ptr)));
-            //if the iterator was consuming then deallocate the memory that was initially allocated to josievec by calculating the capacity.
-            dealloc(self.start_ptr as *mut u8, Layout::array::<T>(self.end_ptr.offset_from_unsigned(self.start_ptr)).expect("Overflow"));
+            drop_range(self.ptr, ptr_distance(self.ptr, self.end_ptr));
         }
+        //the buffer itself is deallocated when buf drops
     }
 }
 
 
-impl<T> IntoIterator for JosieVec<T>{
+impl<T, A: JosieAllocator, G: GrowthPolicy> IntoIterator for JosieVec<T, A, G>{
     type Item = T;
 
-        type IntoIter = JosieVecIter<T>;
+        type IntoIter = JosieVecIter<T, A, G>;
 
-        fn into_iter(self) -> JosieVecIter<T>{
+        fn into_iter(self) -> JosieVecIter<T, A, G>{
             let to_drop = ManuallyDrop::new(self);
             //creates new josieVecIter  with ownership of raw pointer to data, an iteration counter and the length of the current buffer
             let ptr = to_drop.buf.ptr.as_ptr();
             JosieVecIter{
-                start_ptr:ptr,
-                end_ptr: unsafe{ptr.add(to_drop.len)},
+                //moves the buffer out of the josievec, the josievec itself is never dropped so the buffer is only owned once
+                buf: unsafe{read(&to_drop.buf)},
+                //the iterator never grows, the policy is only kept so into_josievec can hand it back
+                policy: unsafe{read(&to_drop.policy)},
+                end_ptr: unsafe{ptr_add(ptr, to_drop.len)},
                 ptr,
             }
         }
 }
 
-impl<T> Iterator for JosieVecIter<T>{
+impl<T, A: JosieAllocator, G: GrowthPolicy> Iterator for JosieVecIter<T, A, G>{
     type Item = T;
 
     fn next(&mut self) -> Option<T>{
@@ -128,16 +301,48 @@ impl<T> Iterator for JosieVecIter<T>{
             return None;
         }
         unsafe{
-            let out = self.ptr.read();
-            self.ptr = self.ptr.add(1);
-            Some(out)
+            Some(read_bump(&mut self.ptr))
         }
         
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let size = unsafe{self.end_ptr.offset_from_unsigned(self.start_ptr)};
+        //counts from the read pointer so only the elements still left are reported
+        let size = unsafe{ptr_distance(self.ptr, self.end_ptr)};
         (size, Some(size))
     }
-    
-}
\ No newline at end of file
+
+    fn nth(&mut self, n:usize) -> Option<T>{
+        let skip = n.min(self.len());
+        let ptr = self.ptr;
+        unsafe{
+            //moves past the skipped elements before dropping them so a panicking drop cant drop them twice
+            self.ptr = ptr_add(ptr, skip);
+            drop_range(ptr, skip);
+        }
+        self.next()
+    }
+}
+
+impl<T, A: JosieAllocator, G: GrowthPolicy> DoubleEndedIterator for JosieVecIter<T, A, G>{
+    fn next_back(&mut self) -> Option<T>{
+        if self.ptr == self.end_ptr{
+            return None;
+        }
+        unsafe{
+            Some(read_back(&mut self.end_ptr))
+        }
+    }
+
+    fn nth_back(&mut self, n:usize) -> Option<T>{
+        let skip = n.min(self.len());
+        unsafe{
+            self.end_ptr = ptr_sub(self.end_ptr, skip);
+            drop_range(self.end_ptr, skip);
+        }
+        self.next_back()
+    }
+}
+
+impl<T, A: JosieAllocator, G: GrowthPolicy> ExactSizeIterator for JosieVecIter<T, A, G>{}
+impl<T, A: JosieAllocator, G: GrowthPolicy> FusedIterator for JosieVecIter<T, A, G>{}
\ No newline at end of file
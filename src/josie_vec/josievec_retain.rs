@@ -0,0 +1,164 @@
+// ===============================
+// RETAIN AND DEDUP
+// -------------------------------
+// In place filtering for josievec. Both walk the vector once, moving kept elements back over the
+// removed ones. Raii guards put the josievec back together if a predicate or a drop panics
+// -------------------------------
+
+use std::ptr::{copy, drop_in_place};
+
+use super::{josievec_alloc::JosieAllocator, josievec_growth::GrowthPolicy, JosieVec};
+
+//raii drop guard for retain, on drop shifts every element that hasnt been checked back over the holes
+//left by deleted elements and sets the length to match
+struct JosieVecRetainGuard<'a, T, A: JosieAllocator, G: GrowthPolicy>{
+    //mutable reference to the josievec
+    josievec:&'a mut JosieVec<T, A, G>,
+    //length of the josievec before retain started
+    original_len:usize,
+    //number of elements that have been checked against the predicate
+    processed:usize,
+    //number of elements that have been deleted
+    deleted:usize,
+}
+
+impl<'a, T, A: JosieAllocator, G: GrowthPolicy> Drop for JosieVecRetainGuard<'a, T, A, G>{
+    fn drop(&mut self){
+        //only does work if something was deleted, if a predicate panics there can be unchecked elements left that need to be shifted back
+        if self.deleted > 0{
+            unsafe{
+                let ptr = self.josievec.buf.ptr.as_ptr();
+                copy(
+                    ptr.add(self.processed),
+                    ptr.add(self.processed - self.deleted),
+                    self.original_len - self.processed,
+                );
+            }
+        }
+        //sets length to the number of elements that were kept
+        self.josievec.len = self.original_len - self.deleted;
+        self.josievec.apply_shrink_policy();
+    }
+}
+
+//raii drop guard for dedup, read is the next element to check and write is where the next kept element goes.
+//on drop shifts everything from read onwards back to write
+struct JosieVecDedupGuard<'a, T, A: JosieAllocator, G: GrowthPolicy>{
+    //mutable reference to the josievec
+    josievec:&'a mut JosieVec<T, A, G>,
+    //length of the josievec before dedup started
+    original_len:usize,
+    //index of the next element to be compared
+    read:usize,
+    //index the next kept element will be written to
+    write:usize,
+}
+
+impl<'a, T, A: JosieAllocator, G: GrowthPolicy> Drop for JosieVecDedupGuard<'a, T, A, G>{
+    fn drop(&mut self){
+        unsafe{
+            let ptr = self.josievec.buf.ptr.as_ptr();
+            //moves all unchecked elements back over the gap left by duplicates
+            copy(ptr.add(self.read), ptr.add(self.write), self.original_len - self.read);
+        }
+        //sets length to the kept elements plus the unchecked ones
+        self.josievec.len = self.write + self.original_len - self.read;
+        self.josievec.apply_shrink_policy();
+    }
+}
+
+impl<T, A: JosieAllocator, G: GrowthPolicy> JosieVec<T, A, G>{
+    ///Keeps only the elements the predicate returns true for, removing the rest in place while preserving order.
+    pub fn retain<F>(&mut self, mut f:F)
+    where F: FnMut(&T) -> bool{
+        self.retain_mut(|element| f(element));
+    }
+
+    ///Keeps only the elements the predicate returns true for, handing the predicate a mutable reference so kept
+    ///elements can be modified on the way through.
+    pub fn retain_mut<F>(&mut self, mut f:F)
+    where F: FnMut(&mut T) -> bool{
+        let original_len = self.len;
+        //sets length to zero so if anything goes wrong before the guard runs no element can be dropped twice
+        self.len = 0;
+        let mut guard = JosieVecRetainGuard{
+            josievec:self,
+            original_len,
+            processed:0,
+            deleted:0,
+        };
+        while guard.processed != original_len{
+            unsafe{
+                let ptr = guard.josievec.buf.ptr.as_ptr();
+                let current = ptr.add(guard.processed);
+                if !f(&mut *current){
+                    //counts the element as processed and deleted before dropping it, so if the drop panics the guard
+                    //doesnt drop it again
+                    guard.processed += 1;
+                    guard.deleted += 1;
+                    drop_in_place(current);
+                    continue;
+                }
+                //moves the kept element back over any holes left by deleted elements
+                if guard.deleted > 0{
+                    copy(current, ptr.add(guard.processed - guard.deleted), 1);
+                }
+                guard.processed += 1;
+            }
+        }
+        //guard drops here and sets length
+    }
+
+    ///Removes consecutive elements that compare equal, keeping the first of each run.
+    #[inline(always)]
+    pub fn dedup(&mut self)
+    where T: PartialEq{
+        self.dedup_by(|a, b| a == b);
+    }
+
+    ///Removes consecutive elements that map to the same key, keeping the first of each run.
+    #[inline(always)]
+    pub fn dedup_by_key<F, K>(&mut self, mut key:F)
+    where F: FnMut(&mut T) -> K, K: PartialEq{
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    ///Removes consecutive elements where same_bucket returns true. same_bucket is handed the element being checked
+    ///first and the last kept element second, if it returns true the first one is removed.
+    pub fn dedup_by<F>(&mut self, mut same_bucket:F)
+    where F: FnMut(&mut T, &mut T) -> bool{
+        let original_len = self.len;
+        //nothing can be a duplicate with fewer than 2 elements
+        if original_len < 2{
+            return;
+        }
+        //sets length to zero so if anything goes wrong before the guard runs no element can be dropped twice
+        self.len = 0;
+        let mut guard = JosieVecDedupGuard{
+            josievec:self,
+            original_len,
+            read:1,
+            write:1,
+        };
+        while guard.read != original_len{
+            unsafe{
+                let ptr = guard.josievec.buf.ptr.as_ptr();
+                let current = ptr.add(guard.read);
+                let previous = ptr.add(guard.write - 1);
+                if same_bucket(&mut *current, &mut *previous){
+                    //counts the element as read before dropping it so a panicking drop isnt dropped again by the guard
+                    guard.read += 1;
+                    drop_in_place(current);
+                    continue;
+                }
+                //moves the kept element back to the write position
+                if guard.read != guard.write{
+                    copy(current, ptr.add(guard.write), 1);
+                }
+                guard.write += 1;
+                guard.read += 1;
+            }
+        }
+        //guard drops here and sets length
+    }
+}
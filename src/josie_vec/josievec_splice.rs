@@ -0,0 +1,126 @@
+// ===============================
+// SPLICE IMPLEMENTATION
+// -------------------------------
+// Replaces a range of the josievec with the contents of an iterator. Built on top of drain, the
+// removed elements are handed out by the drain and the gap is refilled on drop. The tail is moved
+// using size_hint the same way extend reserves, so an exact size replacement only moves it once
+// -------------------------------
+
+use std::{ops::RangeBounds, ptr::copy};
+
+use super::{
+    josievec_alloc::{Global, JosieAllocator},
+    josievec_growth::{Doubling, GrowthPolicy},
+    josievec_drain::JosieVecDrain,
+    JosieVec,
+};
+
+///Iterator over the elements removed by JosieVec::splice. The replacement is written into the josievec when it drops
+pub struct JosieVecSplice<'a, I: Iterator, A: JosieAllocator = Global, G: GrowthPolicy = Doubling>{
+    //drain over the range being replaced
+    pub(crate) drain:JosieVecDrain<'a, I::Item, A, G>,
+    //iterator the gap is refilled from
+    pub(crate) replace_with:I,
+}
+
+impl<'a, I: Iterator, A: JosieAllocator, G: GrowthPolicy> Iterator for JosieVecSplice<'a, I, A, G>{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item>{
+        self.drain.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>){
+        self.drain.size_hint()
+    }
+}
+
+impl<'a, I: Iterator, A: JosieAllocator, G: GrowthPolicy> DoubleEndedIterator for JosieVecSplice<'a, I, A, G>{
+    fn next_back(&mut self) -> Option<Self::Item>{
+        self.drain.next_back()
+    }
+}
+
+impl<'a, I: Iterator, A: JosieAllocator, G: GrowthPolicy> Drop for JosieVecSplice<'a, I, A, G>{
+    fn drop(&mut self){
+        //drops every removed element that wasnt yielded
+        self.drain.by_ref().for_each(drop);
+        unsafe{
+            //with no tail there is no gap to manage, just extend the josievec
+            if self.drain.tail_len == 0{
+                self.drain.josievec.extend(self.replace_with.by_ref());
+                return;
+            }
+            //fills the gap left by the removed range, returns early if the replacement fit inside it
+            if !self.drain.fill(&mut self.replace_with){
+                return;
+            }
+            //matches the replacements size hint the same way extend does
+            match self.replace_with.size_hint(){
+                //if there is nothing left there is nothing to do
+                (_, Some(0)) => return,
+                //with no lower bound there is nothing to reserve up front, fall through and collect the rest
+                (0, _) => (),
+                //otherwise move the tail by the lower bound and fill the gap it leaves. if the size is known exactly
+                //this fills everything and the tail is only moved once
+                (lower_bound, _) =>{
+                    self.drain.shift_tail(lower_bound);
+                    if !self.drain.fill(&mut self.replace_with){
+                        return;
+                    }
+                },
+            }
+            //whatever is left has an unknown size, collects it so the tail can be moved exactly once more
+            let collected = self.replace_with.by_ref().collect::<JosieVec<I::Item>>();
+            if !collected.is_empty(){
+                self.drain.shift_tail(collected.len());
+                self.drain.fill(&mut collected.into_iter());
+            }
+        }
+        //drain drops after this, moving the tail back down to meet the end of the written elements
+    }
+}
+
+impl<'a, T, A: JosieAllocator, G: GrowthPolicy> JosieVecDrain<'a, T, A, G>{
+    //writes elements from replace_with into the gap between the end of the josievec and the start of the tail.
+    //returns true if the gap was filled and false if replace_with ran out first
+    unsafe fn fill<I: Iterator<Item = T>>(&mut self, replace_with:&mut I) -> bool{
+        let base = self.josievec.buf.ptr.as_ptr();
+        while self.josievec.len != self.tail_start{
+            match replace_with.next(){
+                Some(element) =>{
+                    unsafe{base.add(self.josievec.len).write(element)}
+                    //length is bumped per element so a panicking iterator leaves every written element owned
+                    self.josievec.len += 1;
+                },
+                None => return false,
+            }
+        }
+        true
+    }
+
+    //moves the tail additional elements to the right, reserving room for it first
+    unsafe fn shift_tail(&mut self, additional:usize){
+        //reserve counts from the current length so ask for everything up to the end of the moved tail
+        let used = self.tail_start + self.tail_len;
+        self.josievec.reserve(used + additional - self.josievec.len);
+        unsafe{
+            let base = self.josievec.buf.ptr.as_ptr();
+            copy(base.add(self.tail_start), base.add(self.tail_start + additional), self.tail_len);
+        }
+        self.tail_start += additional;
+    }
+}
+
+impl<T, A: JosieAllocator, G: GrowthPolicy> JosieVec<T, A, G>{
+    ///Replaces the elements in range with the contents of replace_with, returning an iterator over the removed
+    ///elements. The replacement can be a different length to the range. It is written into the josievec when the
+    ///returned iterator drops, so the iterator must be dropped for the splice to happen.
+    pub fn splice<R, I>(&mut self, range:R, replace_with:I) -> JosieVecSplice<'_, I::IntoIter, A, G>
+    where R: RangeBounds<usize>, I: IntoIterator<Item = T>{
+        JosieVecSplice{
+            drain:self.drain(range),
+            replace_with:replace_with.into_iter(),
+        }
+    }
+}
@@ -0,0 +1,189 @@
+// ===============================
+// STANDARD TRAITS
+// -------------------------------
+// Everything a josievec needs to stand in for a Vec in maps, sets and assertions. Every trait works
+// on the elements as a slice, so comparing, ordering, hashing and formatting a josievec gives the
+// same answer as doing it to the slice of its contents
+// -------------------------------
+
+use std::{
+    borrow::{Borrow, BorrowMut},
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    io,
+    ops::{Index, IndexMut},
+    slice::SliceIndex,
+};
+
+use super::{
+    josievec_alloc::JosieAllocator,
+    josievec_growth::GrowthPolicy,
+    JosieVec,
+};
+
+//prints the elements instead of the raw buffer
+impl<T: fmt::Debug, A: JosieAllocator, G: GrowthPolicy> fmt::Debug for JosieVec<T, A, G>{
+    fn fmt(&self, f:&mut fmt::Formatter<'_>) -> fmt::Result{
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+//josievecs are equal if their elements are, no matter the allocator or growth policy
+impl<T, U, A1, A2, G1, G2> PartialEq<JosieVec<U, A2, G2>> for JosieVec<T, A1, G1>
+where T: PartialEq<U>, A1: JosieAllocator, A2: JosieAllocator, G1: GrowthPolicy, G2: GrowthPolicy{
+    #[inline]
+    fn eq(&self, other:&JosieVec<U, A2, G2>) -> bool{
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T, U, A: JosieAllocator, G: GrowthPolicy> PartialEq<[U]> for JosieVec<T, A, G>
+where T: PartialEq<U>{
+    #[inline]
+    fn eq(&self, other:&[U]) -> bool{
+        self.as_slice() == other
+    }
+}
+
+impl<T, U, A: JosieAllocator, G: GrowthPolicy> PartialEq<&[U]> for JosieVec<T, A, G>
+where T: PartialEq<U>{
+    #[inline]
+    fn eq(&self, other:&&[U]) -> bool{
+        self.as_slice() == *other
+    }
+}
+
+impl<T, U, A: JosieAllocator, G: GrowthPolicy, const N:usize> PartialEq<[U; N]> for JosieVec<T, A, G>
+where T: PartialEq<U>{
+    #[inline]
+    fn eq(&self, other:&[U; N]) -> bool{
+        self.as_slice() == other
+    }
+}
+
+impl<T, U, A: JosieAllocator, G: GrowthPolicy, const N:usize> PartialEq<&[U; N]> for JosieVec<T, A, G>
+where T: PartialEq<U>{
+    #[inline]
+    fn eq(&self, other:&&[U; N]) -> bool{
+        self.as_slice() == *other
+    }
+}
+
+impl<T, U, A: JosieAllocator, G: GrowthPolicy> PartialEq<Vec<U>> for JosieVec<T, A, G>
+where T: PartialEq<U>{
+    #[inline]
+    fn eq(&self, other:&Vec<U>) -> bool{
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, A: JosieAllocator, G: GrowthPolicy> Eq for JosieVec<T, A, G>{}
+
+//orders lexicographically like slices
+impl<T, A1, A2, G1, G2> PartialOrd<JosieVec<T, A2, G2>> for JosieVec<T, A1, G1>
+where T: PartialOrd, A1: JosieAllocator, A2: JosieAllocator, G1: GrowthPolicy, G2: GrowthPolicy{
+    #[inline]
+    fn partial_cmp(&self, other:&JosieVec<T, A2, G2>) -> Option<Ordering>{
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord, A: JosieAllocator, G: GrowthPolicy> Ord for JosieVec<T, A, G>{
+    #[inline]
+    fn cmp(&self, other:&Self) -> Ordering{
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+//hashes exactly like the slice so a josievec key can be looked up with a slice through Borrow
+impl<T: Hash, A: JosieAllocator, G: GrowthPolicy> Hash for JosieVec<T, A, G>{
+    #[inline]
+    fn hash<H: Hasher>(&self, state:&mut H){
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T, A: JosieAllocator, G: GrowthPolicy> AsRef<[T]> for JosieVec<T, A, G>{
+    fn as_ref(&self) -> &[T]{
+        self.as_slice()
+    }
+}
+
+impl<T, A: JosieAllocator, G: GrowthPolicy> AsMut<[T]> for JosieVec<T, A, G>{
+    fn as_mut(&mut self) -> &mut [T]{
+        self.as_mut_slice()
+    }
+}
+
+impl<T, A: JosieAllocator, G: GrowthPolicy> Borrow<[T]> for JosieVec<T, A, G>{
+    fn borrow(&self) -> &[T]{
+        self.as_slice()
+    }
+}
+
+impl<T, A: JosieAllocator, G: GrowthPolicy> BorrowMut<[T]> for JosieVec<T, A, G>{
+    fn borrow_mut(&mut self) -> &mut [T]{
+        self.as_mut_slice()
+    }
+}
+
+//indexes with anything a slice can be indexed with, single positions and every kind of range
+impl<T, I: SliceIndex<[T]>, A: JosieAllocator, G: GrowthPolicy> Index<I> for JosieVec<T, A, G>{
+    type Output = I::Output;
+    #[inline]
+    fn index(&self, index:I) -> &Self::Output{
+        Index::index(self.as_slice(), index)
+    }
+}
+
+impl<T, I: SliceIndex<[T]>, A: JosieAllocator, G: GrowthPolicy> IndexMut<I> for JosieVec<T, A, G>{
+    #[inline]
+    fn index_mut(&mut self, index:I) -> &mut Self::Output{
+        IndexMut::index_mut(self.as_mut_slice(), index)
+    }
+}
+
+//moves the elements out into an array if the length matches exactly, otherwise hands the josievec back
+impl<T, A: JosieAllocator, G: GrowthPolicy, const N:usize> TryFrom<JosieVec<T, A, G>> for [T; N]{
+    type Error = JosieVec<T, A, G>;
+    fn try_from(mut josievec:JosieVec<T, A, G>) -> Result<Self, Self::Error>{
+        if josievec.len != N{
+            return Err(josievec);
+        }
+        unsafe{
+            //the array owns the elements now, setting length to zero leaves only the allocation for the josievec to free
+            josievec.set_len(0);
+            let array = josievec.as_ptr().cast::<[T; N]>().read();
+            Ok(array)
+        }
+    }
+}
+
+//copies the referenced elements in, lets a josievec be extended straight from an iterator over a slice
+impl<'a, T: Copy + 'a, A: JosieAllocator, G: GrowthPolicy> Extend<&'a T> for JosieVec<T, A, G>{
+    fn extend<I>(&mut self, iter:I)
+    where I: IntoIterator<Item = &'a T>{
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+//lets a byte josievec be used as an in memory writer, every write is appended to the end
+impl<A: JosieAllocator, G: GrowthPolicy> io::Write for JosieVec<u8, A, G>{
+    #[inline]
+    fn write(&mut self, buf:&[u8]) -> io::Result<usize>{
+        self.extend_from_copy_slice(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf:&[u8]) -> io::Result<()>{
+        self.extend_from_copy_slice(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()>{
+        Ok(())
+    }
+}
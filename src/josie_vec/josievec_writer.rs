@@ -0,0 +1,95 @@
+// ===============================
+// JOSIEVEC WRITER
+// -------------------------------
+// Safe sink for bulk writes into spare capacity. Wraps the same raii guard bulk_extend_guarded uses,
+// so the length is committed from the write pointer when the writer drops, including on panic. The
+// writer owns the pointer, so it can only ever hold valid T, only move forwards and never run past
+// the end of the buffer. When it runs out of room it grows the josievec itself.
+// -------------------------------
+
+use super::{
+    josievec_alloc::{Global, JosieAllocator},
+    josievec_growth::{Doubling, GrowthPolicy},
+    josievec_extend::{ExtendType, JosieVecGuard},
+    ptr_distance, write_bump, JosieVec,
+};
+
+///Safe writer into the spare capacity of a josievec, returned by JosieVec::writer. Every element written is
+///added to the josievec length when the writer drops
+pub struct JosieVecWriter<'a, T, A: JosieAllocator = Global, G: GrowthPolicy = Doubling>{
+    //raii guard holding the write pointer, sets length on drop
+    guard:JosieVecGuard<'a, T, A, G>,
+    //length of the josievec when the writer was created
+    original_len:usize,
+}
+
+impl<'a, T, A: JosieAllocator, G: GrowthPolicy> JosieVecWriter<'a, T, A, G>{
+    ///Writes value to the next free slot, growing the josievec if there is no capacity left
+    #[inline(always)]
+    pub fn push(&mut self, value:T){
+        if self.remaining() == 0{
+            self.grow(1);
+        }
+        unsafe{write_bump(&mut self.guard.ptr, value)}
+    }
+
+    ///Clones every element of slice into the free slots, growing once up front if they dont all fit
+    pub fn write_slice(&mut self, slice:&[T])
+    where T: Clone{
+        if self.remaining() < slice.len(){
+            self.grow(slice.len());
+        }
+        for element in slice{
+            unsafe{write_bump(&mut self.guard.ptr, element.clone())}
+        }
+    }
+
+    ///Number of elements that can be written before the writer has to grow the josievec
+    #[inline(always)]
+    pub fn remaining(&self) -> usize{
+        self.guard.josievec.buf.capacity() - self.current_len()
+    }
+
+    ///Number of elements written so far
+    #[inline(always)]
+    pub fn written(&self) -> usize{
+        self.current_len() - self.original_len
+    }
+
+    //length the josievec will have once the writer drops
+    #[inline(always)]
+    fn current_len(&self) -> usize{
+        self.guard.start_len + unsafe{ptr_distance(self.guard.start_ptr, self.guard.ptr)}
+    }
+
+    //commits what has been written so far, reserves room for additional more elements and moves the pointers
+    //into the new buffer
+    #[cold]
+    fn grow(&mut self, additional:usize){
+        self.guard.rearm();
+        self.guard.josievec.reserve(additional);
+        //reserve can move the buffer so the pointers are rebuilt from the josievec
+        self.guard.rearm();
+    }
+}
+
+impl<T, A: JosieAllocator, G: GrowthPolicy> JosieVec<T, A, G>{
+    ///Returns a safe writer into the spare capacity of the josievec. Exact reserves room for that many elements up
+    ///front, Ammortized grows the josievec if it is full. The writer grows the josievec again on its own if it runs
+    ///out of room, and the length is updated with everything written once it drops. For Example on how to use
+    ///this, check out fibonacci_test.
+    pub fn writer(&mut self, extend_type:ExtendType) -> JosieVecWriter<'_, T, A, G>{
+        match extend_type{
+            ExtendType::Exact(elements) => self.reserve(elements),
+            ExtendType::Ammortized =>{
+                if self.len == self.buf.capacity(){
+                    self.grow_amortized();
+                }
+            }
+        }
+        JosieVecWriter{
+            original_len:self.len,
+            guard:JosieVecGuard::arm(self),
+        }
+    }
+}
@@ -0,0 +1,611 @@
+// ===============================
+//       JOSIE VEC MAP
+// -------------------------------
+// Ordered map stored flat in a josievec of (key, value) pairs sorted by key. Lookups are binary
+// searches and iteration walks contiguous memory, so for small maps that are mostly read it beats
+// hashing every key. Inserting and removing shift the tail of the josievec so they are O(n). Keys are
+// only ever handed out behind shared references, so nothing outside this file can break the order
+// -------------------------------
+
+use std::{
+    borrow::Borrow,
+    fmt,
+    hash::{Hash, Hasher},
+    mem::{replace, swap},
+    ops::{Index, RangeBounds},
+    slice,
+};
+
+use crate::{
+    entry_iterator,
+    josie_sorted_vec::sorted_range,
+    josie_vec::{josievec_error::JosieTryReserveError, josievec_iter::JosieVecIter, JosieVec},
+};
+
+///Map with its entries kept in ascending key order in a josievec
+pub struct JosieVecMap<K, V> {
+    entries: JosieVec<(K, V)>,
+}
+
+impl<K, V> JosieVecMap<K, V> {
+    ///Creates an empty JosieVecMap without allocating
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self {
+            entries: JosieVec::new(),
+        }
+    }
+
+    ///Creates an empty JosieVecMap with room for cap entries
+    pub fn with_capacity(cap: usize) -> Self {
+        Self {
+            entries: JosieVec::with_capacity(cap),
+        }
+    }
+
+    ///Number of entries in the map
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    ///Returns true if the map has no entries
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    ///Number of entries that fit before the josievec reallocates
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.entries.capacity()
+    }
+
+    ///Reserves room for at least additional more entries
+    #[inline(always)]
+    pub fn reserve(&mut self, additional: usize) {
+        self.entries.reserve(additional)
+    }
+
+    ///Reserves room for at least additional more entries, returning an error instead of panicking or
+    ///aborting if the josievec can not grow
+    #[inline(always)]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), JosieTryReserveError> {
+        self.entries.try_reserve(additional)
+    }
+
+    ///Shrinks the capacity to fit the entries there are
+    #[inline(always)]
+    pub fn shrink_to_fit(&mut self) {
+        self.entries.shrink_to_fit()
+    }
+
+    ///Returns the entries in key order
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[(K, V)] {
+        self.entries.as_slice()
+    }
+
+    ///Returns an iterator over the entries in key order
+    pub fn iter(&self) -> JosieVecMapIterRef<'_, K, V> {
+        JosieVecMapIterRef {
+            iter: self.entries.as_slice().iter(),
+        }
+    }
+
+    ///Returns an iterator over the entries in key order with mutable values
+    pub fn iter_mut(&mut self) -> JosieVecMapIterMut<'_, K, V> {
+        JosieVecMapIterMut {
+            iter: self.entries.as_mut_slice().iter_mut(),
+        }
+    }
+
+    ///Returns an iterator over the keys in order
+    pub fn keys(&self) -> JosieVecMapKeys<'_, K, V> {
+        JosieVecMapKeys {
+            iter: self.entries.as_slice().iter(),
+        }
+    }
+
+    ///Returns an iterator over the values in key order
+    pub fn values(&self) -> JosieVecMapValues<'_, K, V> {
+        JosieVecMapValues {
+            iter: self.entries.as_slice().iter(),
+        }
+    }
+
+    ///Returns an iterator over mutable references to the values in key order
+    pub fn values_mut(&mut self) -> JosieVecMapValuesMut<'_, K, V> {
+        JosieVecMapValuesMut {
+            iter: self.entries.as_mut_slice().iter_mut(),
+        }
+    }
+
+    ///Returns the entry with the smallest key
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        self.entries.first().map(|(key, value)| (key, value))
+    }
+
+    ///Returns the entry with the greatest key
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        self.entries.last().map(|(key, value)| (key, value))
+    }
+
+    ///Removes and returns the entry with the smallest key. O(n)
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(self.entries.remove(0))
+    }
+
+    ///Removes and returns the entry with the greatest key. O(1)
+    #[inline(always)]
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        self.entries.pop()
+    }
+
+    ///Keeps only the entries the predicate returns true for. The predicate can change the values
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.entries.retain_mut(|(key, value)| f(key, value))
+    }
+
+    ///Drops every entry, keeping the allocation
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.entries.clear()
+    }
+
+    ///Returns the josievec the entries are stored in, in key order
+    #[inline(always)]
+    pub fn into_josievec(self) -> JosieVec<(K, V)> {
+        self.entries
+    }
+}
+
+impl<K: Ord, V> JosieVecMap<K, V> {
+    ///Sorts the entries of josievec by key. When a key shows up more than once the last value wins, like
+    ///inserting them in order would. Reuses the allocation. O(n log n)
+    pub fn from_unsorted(mut josievec: JosieVec<(K, V)>) -> Self {
+        josievec.sort_by(|(a, _), (b, _)| a.cmp(b));
+        dedup_keys(&mut josievec);
+        Self { entries: josievec }
+    }
+
+    //position of key, or Err with the position it would be inserted at
+    fn search<Q>(&self, key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.entries
+            .binary_search_by(|(found, _)| found.borrow().cmp(key))
+    }
+
+    ///Returns a reference to the value for key. O(log n)
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    ///Returns the stored key and a reference to the value for key
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let (key, value) = &self.entries[self.search(key).ok()?];
+        Some((key, value))
+    }
+
+    ///Returns a mutable reference to the value for key
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+        Some(&mut self.entries[index].1)
+    }
+
+    ///Returns true if the map has an entry for key
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.search(key).is_ok()
+    }
+
+    ///Returns an iterator over the entries whose keys fall in range, in key order. A range that ends
+    ///before it starts is empty
+    pub fn range<Q, R>(&self, range: R) -> JosieVecMapIterRef<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let range = sorted_range(&self.entries, range, |(key, _)| key.borrow());
+        JosieVecMapIterRef {
+            iter: self.entries[range].iter(),
+        }
+    }
+
+    ///Returns the entry for key, to insert into or change in place
+    pub fn entry(&mut self, key: K) -> JosieVecMapEntry<'_, K, V> {
+        match self.search(&key) {
+            Ok(index) => JosieVecMapEntry::Occupied(JosieVecMapOccupiedEntry {
+                entries: &mut self.entries,
+                index,
+            }),
+            Err(index) => JosieVecMapEntry::Vacant(JosieVecMapVacantEntry {
+                entries: &mut self.entries,
+                index,
+                key,
+            }),
+        }
+    }
+
+    ///Inserts value for key, returning the old value if the key was already there. The stored key is
+    ///kept. O(n)
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.entry(key) {
+            JosieVecMapEntry::Occupied(mut entry) => Some(entry.insert(value)),
+            JosieVecMapEntry::Vacant(entry) => {
+                entry.insert(value);
+                None
+            }
+        }
+    }
+
+    ///Removes the entry for key and returns its value. O(n)
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    ///Removes the entry for key and returns the stored key and its value. O(n)
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+        Some(self.entries.remove(index))
+    }
+}
+
+//drops every entry whose key equals the key before it, moving its value into the entry kept so the last
+//value of each run wins. entries has to be sorted by key
+fn dedup_keys<K: Ord, V>(entries: &mut JosieVec<(K, V)>) {
+    entries.dedup_by(|(key, value), (kept_key, kept_value)| {
+        let same = key == kept_key;
+        if same {
+            swap(value, kept_value);
+        }
+        same
+    });
+}
+
+// ===============================
+// ENTRY API
+// -------------------------------
+
+///A view into one entry of a JosieVecMap, returned by JosieVecMap::entry
+pub enum JosieVecMapEntry<'a, K, V> {
+    ///The key is in the map
+    Occupied(JosieVecMapOccupiedEntry<'a, K, V>),
+    ///The key isnt in the map
+    Vacant(JosieVecMapVacantEntry<'a, K, V>),
+}
+
+///An entry whose key is in the map
+pub struct JosieVecMapOccupiedEntry<'a, K, V> {
+    entries: &'a mut JosieVec<(K, V)>,
+    index: usize,
+}
+
+///An entry whose key isnt in the map yet
+pub struct JosieVecMapVacantEntry<'a, K, V> {
+    entries: &'a mut JosieVec<(K, V)>,
+    //position the key is inserted at to keep the entries sorted
+    index: usize,
+    key: K,
+}
+
+impl<'a, K, V> JosieVecMapEntry<'a, K, V> {
+    ///Key the entry was looked up with, or the stored one if it is occupied
+    pub fn key(&self) -> &K {
+        match self {
+            Self::Occupied(entry) => entry.key(),
+            Self::Vacant(entry) => entry.key(),
+        }
+    }
+
+    ///Value of the entry, inserting default where it keeps the keys sorted if it is vacant
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    ///Like or_insert, but default is only called if the entry is vacant
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        self.or_insert_with_key(|_| default())
+    }
+
+    ///Like or_insert_with, with default handed the key
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => {
+                let value = default(&entry.key);
+                entry.insert(value)
+            }
+        }
+    }
+
+    ///Like or_insert with V::default()
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    ///Runs f on the value if there is one, then hands the entry back
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Self::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K, V> JosieVecMapOccupiedEntry<'a, K, V> {
+    ///The key stored in the map
+    pub fn key(&self) -> &K {
+        &self.entries[self.index].0
+    }
+
+    ///Returns the position of the entry in key order
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    ///Shared reference to the value
+    pub fn get(&self) -> &V {
+        &self.entries[self.index].1
+    }
+
+    ///Mutable reference to the value
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.entries[self.index].1
+    }
+
+    ///Mutable reference to the value for as long as the map stays borrowed
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.entries[self.index].1
+    }
+
+    ///Swaps in value and returns the one it replaced
+    pub fn insert(&mut self, value: V) -> V {
+        replace(self.get_mut(), value)
+    }
+
+    ///Removes the entry from the map and returns its value. O(n)
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    ///Removes the entry from the map and returns the stored key and its value. O(n)
+    pub fn remove_entry(self) -> (K, V) {
+        self.entries.remove(self.index)
+    }
+}
+
+impl<'a, K, V> JosieVecMapVacantEntry<'a, K, V> {
+    ///Returns the key that would be inserted
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    ///Returns the position the entry would be inserted at in key order
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    ///Takes the key back without inserting anything
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    ///Inserts value for the key where it keeps the entries sorted and returns a mutable reference to it.
+    ///O(n)
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.entries.insert(self.index, (self.key, value));
+        &mut self.entries[self.index].1
+    }
+}
+
+// ===============================
+// ITERATORS
+// -------------------------------
+
+///Iterator over references to the entries of a JosieVecMap, in key order
+#[derive(Clone)]
+pub struct JosieVecMapIterRef<'a, K, V> {
+    iter: slice::Iter<'a, (K, V)>,
+}
+
+entry_iterator!(JosieVecMapIterRef<'a, K, V>, (&'a K, &'a V), |(key, value)| (key, value));
+
+///Iterator over the entries of a JosieVecMap with mutable values, in key order
+pub struct JosieVecMapIterMut<'a, K, V> {
+    iter: slice::IterMut<'a, (K, V)>,
+}
+
+entry_iterator!(JosieVecMapIterMut<'a, K, V>, (&'a K, &'a mut V), |(key, value)| (&*key, value));
+
+///Iterator over the keys of a JosieVecMap, in order
+#[derive(Clone)]
+pub struct JosieVecMapKeys<'a, K, V> {
+    iter: slice::Iter<'a, (K, V)>,
+}
+
+entry_iterator!(JosieVecMapKeys<'a, K, V>, &'a K, |(key, _)| key);
+
+///Iterator over the values of a JosieVecMap, in key order
+#[derive(Clone)]
+pub struct JosieVecMapValues<'a, K, V> {
+    iter: slice::Iter<'a, (K, V)>,
+}
+
+entry_iterator!(JosieVecMapValues<'a, K, V>, &'a V, |(_, value)| value);
+
+///Iterator over mutable references to the values of a JosieVecMap, in key order
+pub struct JosieVecMapValuesMut<'a, K, V> {
+    iter: slice::IterMut<'a, (K, V)>,
+}
+
+entry_iterator!(JosieVecMapValuesMut<'a, K, V>, &'a mut V, |(_, value)| value);
+
+///Owning iterator over the entries of a JosieVecMap, in key order
+pub struct JosieVecMapIter<K, V> {
+    iter: JosieVecIter<(K, V)>,
+}
+
+entry_iterator!(JosieVecMapIter<K, V>, (K, V), |entry| entry);
+
+impl<K, V> IntoIterator for JosieVecMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = JosieVecMapIter<K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        JosieVecMapIter {
+            iter: self.entries.into_iter(),
+        }
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a JosieVecMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = JosieVecMapIterRef<'a, K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a mut JosieVecMap<K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = JosieVecMapIterMut<'a, K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+// ===============================
+// TRAITS
+// -------------------------------
+
+impl<K, V> Default for JosieVecMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone, V: Clone> Clone for JosieVecMap<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for JosieVecMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+//both maps are sorted by key so equal maps have equal entries in the same order
+impl<K: PartialEq, V: PartialEq> PartialEq for JosieVecMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for JosieVecMap<K, V> {}
+
+impl<K: Hash, V: Hash> Hash for JosieVecMap<K, V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<K, Q, V> Index<&Q> for JosieVecMap<K, V>
+where
+    K: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    type Output = V;
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("Key is not in the JosieVecMap")
+    }
+}
+
+//sorts from unsorted in place
+impl<K: Ord, V> From<JosieVec<(K, V)>> for JosieVecMap<K, V> {
+    fn from(josievec: JosieVec<(K, V)>) -> Self {
+        Self::from_unsorted(josievec)
+    }
+}
+
+impl<K: Ord, V, const N: usize> From<[(K, V); N]> for JosieVecMap<K, V> {
+    fn from(array: [(K, V); N]) -> Self {
+        Self::from_iter(array)
+    }
+}
+
+impl<K, V> From<JosieVecMap<K, V>> for JosieVec<(K, V)> {
+    fn from(map: JosieVecMap<K, V>) -> Self {
+        map.entries
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for JosieVecMap<K, V> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        self.entries.extend(iter);
+        //a stable sort leaves each stored entry in front of new ones with its key, so dedup_keys ends up with
+        //the stored key and the newest value, same as insert
+        self.entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        dedup_keys(&mut self.entries);
+    }
+}
+
+impl<'a, K: Ord + Copy, V: Copy> Extend<(&'a K, &'a V)> for JosieVecMap<K, V> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (&'a K, &'a V)>,
+    {
+        self.extend(iter.into_iter().map(|(key, value)| (*key, *value)))
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for JosieVecMap<K, V> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        Self::from_unsorted(JosieVec::from_iter(iter))
+    }
+}
@@ -1,8 +1,27 @@
+pub mod array_josie_vec;
+pub mod josie_binary_heap;
+pub mod josie_deque;
+pub mod josie_hash_map;
+pub mod josie_hash_set;
+pub mod josie_index_map;
+pub mod josie_index_set;
+pub mod josie_matrix;
+pub mod josie_slab;
+mod josie_slice_iter;
+pub mod josie_soa;
+pub mod josie_sorted_vec;
+pub mod josie_string;
 pub mod josie_vec;
+pub mod josie_vec_map;
+pub mod small_josie_vec;
 pub mod tests;
 ///supports array type notation and from an iterator directly.
 /// ```
-/// let jv = josievec![1, 2, 3], let jv = josievec![0;4], let jv = josievec!(from iterator)
+/// use josie_collections::josievec;
+/// let jv = josievec![1, 2, 3];
+/// let jv = josievec![0; 4];
+/// let jv = josievec!(from 0..4);
+/// ```
 #[macro_export]
 macro_rules! josievec {
         (from $iter:expr) =>{
@@ -20,4 +39,60 @@ macro_rules! josievec {
             $crate::josie_vec::JosieVec::from_iter([$element;$length].into_iter())
         }
     };
-}
\ No newline at end of file
+}
+
+///Creates an ArrayJosieVec with the same forms as josievec!. The capacity comes from the type the
+///result is assigned to, and building one with more elements than its capacity panics.
+/// ```
+/// use josie_collections::{array_josie_vec::ArrayJosieVec, array_josievec};
+/// let av: ArrayJosieVec<i32, 4> = array_josievec![1, 2, 3];
+/// let av: ArrayJosieVec<i32, 4> = array_josievec![0; 4];
+/// let av: ArrayJosieVec<i32, 8> = array_josievec!(from 0..4);
+/// ```
+#[macro_export]
+macro_rules! array_josievec {
+        (from $iter:expr) =>{
+        {
+           $crate::array_josie_vec::ArrayJosieVec::from_iter($iter)
+        }
+    };
+        [$($element:expr),*$(,)?] => {
+        {
+            $crate::array_josie_vec::ArrayJosieVec::from_iter([$($element),*])
+        }
+    };
+    [$element:expr;$length:expr] =>{
+        {
+            $crate::array_josie_vec::ArrayJosieVec::from_iter(::std::iter::repeat_n($element, $length))
+        }
+    };
+}
+
+//implements the iterator traits for a map iterator that wraps the iterator over its entries in a field named
+//iter and maps each item. shared by JosieIndexMap and JosieVecMap
+macro_rules! entry_iterator {
+    ($name:ident<$($lifetime:lifetime,)? K, V>, $item:ty, |$entry:pat_param| $map:expr) => {
+        impl<$($lifetime,)? K, V> ::std::iter::Iterator for $name<$($lifetime,)? K, V> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.iter.next().map(|$entry| $map)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.iter.size_hint()
+            }
+        }
+
+        impl<$($lifetime,)? K, V> ::std::iter::DoubleEndedIterator for $name<$($lifetime,)? K, V> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.iter.next_back().map(|$entry| $map)
+            }
+        }
+
+        impl<$($lifetime,)? K, V> ::std::iter::ExactSizeIterator for $name<$($lifetime,)? K, V> {}
+        impl<$($lifetime,)? K, V> ::std::iter::FusedIterator for $name<$($lifetime,)? K, V> {}
+    };
+}
+
+pub(crate) use entry_iterator;
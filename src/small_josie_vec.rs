@@ -0,0 +1,555 @@
+// ===============================
+//       SMALL JOSIEVEC
+// -------------------------------
+// Keeps up to N elements in an inline array and only spills to a heap josievec once it runs out of
+// room. Most small vectors never allocate at all. Everything that only needs the elements goes
+// through the slice, so the inline and spilled states behave exactly the same
+// -------------------------------
+
+use std::{
+    fmt,
+    iter::FusedIterator,
+    mem::{ManuallyDrop, MaybeUninit},
+    ops::{Deref, DerefMut, RangeBounds},
+    ptr::{copy, copy_nonoverlapping, drop_in_place, read, slice_from_raw_parts_mut},
+    slice::{from_raw_parts, from_raw_parts_mut},
+};
+
+use crate::{
+    josie_slice_iter::{SliceCursor, SliceDrain, SliceDrainSource},
+    josie_vec::{josievec_iter::JosieVecIter, range_from_bounds, JosieVec},
+};
+
+///Vector that stores up to N elements inline before spilling to a heap allocated JosieVec
+pub struct SmallJosieVec<T, const N: usize> {
+    data: SmallJosieVecData<T, N>,
+}
+
+//where the elements currently live
+enum SmallJosieVecData<T, const N: usize> {
+    //the first len elements of buf are initialized
+    Inline {
+        buf: [MaybeUninit<T>; N],
+        len: usize,
+    },
+    Heap(JosieVec<T>),
+}
+
+impl<T, const N: usize> SmallJosieVec<T, N> {
+    ///Creates an empty SmallJosieVec with all of its capacity inline
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            data: SmallJosieVecData::Inline {
+                buf: [const { MaybeUninit::uninit() }; N],
+                len: 0,
+            },
+        }
+    }
+
+    ///Creates an empty SmallJosieVec with room for at least cap elements, spilling straight away if cap is
+    ///more than N
+    pub fn with_capacity(cap: usize) -> Self {
+        if cap <= N {
+            Self::new()
+        } else {
+            Self {
+                data: SmallJosieVecData::Heap(JosieVec::with_capacity(cap)),
+            }
+        }
+    }
+
+    ///Returns true if the elements have spilled to the heap
+    #[inline(always)]
+    pub const fn spilled(&self) -> bool {
+        matches!(self.data, SmallJosieVecData::Heap(_))
+    }
+
+    ///Number of elements in the SmallJosieVec
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        match &self.data {
+            SmallJosieVecData::Inline { len, .. } => *len,
+            SmallJosieVecData::Heap(josievec) => josievec.len(),
+        }
+    }
+
+    ///Returns true if there are no elements
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    ///Number of elements that fit before the next allocation. N while inline
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        match &self.data {
+            SmallJosieVecData::Inline { .. } => N,
+            SmallJosieVecData::Heap(josievec) => josievec.capacity(),
+        }
+    }
+
+    ///Returns a pointer to the first element, inline or on the heap
+    #[inline(always)]
+    pub const fn as_ptr(&self) -> *const T {
+        match &self.data {
+            SmallJosieVecData::Inline { buf, .. } => buf.as_ptr().cast(),
+            SmallJosieVecData::Heap(josievec) => josievec.as_ptr(),
+        }
+    }
+
+    ///Returns a mutable pointer to the first element, inline or on the heap
+    #[inline(always)]
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        match &mut self.data {
+            SmallJosieVecData::Inline { buf, .. } => buf.as_mut_ptr().cast(),
+            SmallJosieVecData::Heap(josievec) => josievec.as_mut_ptr(),
+        }
+    }
+
+    ///Returns slice of the current contents
+    #[inline(always)]
+    pub const fn as_slice(&self) -> &[T] {
+        unsafe { from_raw_parts(self.as_ptr(), self.len()) }
+    }
+
+    ///Returns mutable slice of the current contents
+    #[inline(always)]
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { from_raw_parts_mut(self.as_mut_ptr(), self.len()) }
+    }
+
+    ///Sets the length without touching the elements
+    ///
+    /// # Safety
+    ///len must be less than or equal to capacity and every element up to len must be initialized.
+    #[inline(always)]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        match &mut self.data {
+            SmallJosieVecData::Inline { len, .. } => *len = new_len,
+            SmallJosieVecData::Heap(josievec) => unsafe { josievec.set_len(new_len) },
+        }
+    }
+
+    ///Reserves room for at least additional more elements, spilling to the heap if they dont fit inline
+    pub fn reserve(&mut self, additional: usize) {
+        let len = self.len();
+        let required = len.checked_add(additional).expect("Overflow");
+        if required <= self.capacity() {
+            return;
+        }
+        match &mut self.data {
+            //spills with room to keep growing so pushing past N doesnt spill and then reallocate straight away
+            SmallJosieVecData::Inline { buf, len } => {
+                let mut josievec = JosieVec::with_capacity(required.max(N * 2));
+                unsafe {
+                    copy_nonoverlapping(buf.as_ptr().cast(), josievec.as_mut_ptr(), *len);
+                    josievec.set_len(*len);
+                }
+                //the inline array holds MaybeUninit so replacing it never drops the moved elements
+                self.data = SmallJosieVecData::Heap(josievec);
+            }
+            SmallJosieVecData::Heap(josievec) => josievec.reserve(additional),
+        }
+    }
+
+    ///Pushes an element onto the end, spilling to the heap if the inline storage is full
+    #[inline]
+    pub fn push(&mut self, element: T) {
+        match &mut self.data {
+            SmallJosieVecData::Inline { buf, len } if *len < N => {
+                buf[*len].write(element);
+                *len += 1;
+            }
+            SmallJosieVecData::Heap(josievec) => josievec.push(element),
+            SmallJosieVecData::Inline { .. } => {
+                self.reserve(1);
+                self.push(element);
+            }
+        }
+    }
+
+    ///Pops the last element. Returns None if there are no elements left
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        match &mut self.data {
+            SmallJosieVecData::Inline { buf, len } => {
+                if *len == 0 {
+                    return None;
+                }
+                *len -= 1;
+                Some(unsafe { buf[*len].assume_init_read() })
+            }
+            SmallJosieVecData::Heap(josievec) => josievec.pop(),
+        }
+    }
+
+    ///Inserts element at index, shifting every element after it one to the right. Panics if index is
+    ///greater than the length
+    pub fn insert(&mut self, index: usize, element: T) {
+        let len = self.len();
+        if index > len {
+            panic!("Tried to insert out of bounds element")
+        }
+        self.reserve(1);
+        unsafe {
+            let ptr = self.as_mut_ptr().add(index);
+            copy(ptr, ptr.add(1), len - index);
+            ptr.write(element);
+            self.set_len(len + 1);
+        }
+    }
+
+    ///Removes and returns the element at index, shifting every element after it one to the left
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        if index >= len {
+            panic!("Tried to remove out of bounds element")
+        }
+        unsafe {
+            self.set_len(len - 1);
+            let ptr = self.as_mut_ptr().add(index);
+            let out = ptr.read();
+            copy(ptr.add(1), ptr, len - index - 1);
+            out
+        }
+    }
+
+    ///Removes and returns the element at index, filling the gap with the last element
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        if index >= len {
+            panic!("Tried to swap remove out of bounds element")
+        }
+        unsafe {
+            self.set_len(len - 1);
+            let ptr = self.as_mut_ptr();
+            let out = ptr.add(index).read();
+            copy(ptr.add(len - 1), ptr.add(index), 1);
+            out
+        }
+    }
+
+    ///Drops every element past len
+    pub fn truncate(&mut self, len: usize) {
+        let old_len = self.len();
+        if len < old_len {
+            unsafe {
+                //sets the length first so a panicking drop cant drop anything twice
+                self.set_len(len);
+                drop_in_place(slice_from_raw_parts_mut(
+                    self.as_mut_ptr().add(len),
+                    old_len - len,
+                ));
+            }
+        }
+    }
+
+    ///Drops every element. Spilled capacity is kept
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    ///Moves the elements back inline if they fit, otherwise shrinks the heap allocation to the length
+    pub fn shrink_to_fit(&mut self) {
+        if let SmallJosieVecData::Heap(josievec) = &mut self.data {
+            let len = josievec.len();
+            if len > N {
+                josievec.shrink_to_fit();
+                return;
+            }
+            let mut buf = [const { MaybeUninit::uninit() }; N];
+            unsafe {
+                copy_nonoverlapping(josievec.as_ptr(), buf.as_mut_ptr().cast(), len);
+                //the elements are owned by the inline array now, the josievec only frees its buffer
+                josievec.set_len(0);
+            }
+            self.data = SmallJosieVecData::Inline { buf, len };
+        }
+    }
+
+    ///Removes the elements in range and returns them as an iterator. Whatever the iterator doesnt yield is
+    ///dropped when it is, and the elements after the range are moved back to close the gap.
+    pub fn drain<R>(&mut self, range: R) -> SmallJosieVecDrain<'_, T, N>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = range_from_bounds(range, self.len());
+        SmallJosieVecDrain {
+            drain: SliceDrain::new(self, range),
+        }
+    }
+
+    ///Turns the SmallJosieVec into a JosieVec, reusing the heap buffer if it has already spilled
+    pub fn into_josievec(self) -> JosieVec<T> {
+        let this = ManuallyDrop::new(self);
+        match unsafe { read(&this.data) } {
+            SmallJosieVecData::Inline { buf, len } => {
+                let mut josievec = JosieVec::with_capacity(len);
+                unsafe {
+                    copy_nonoverlapping(buf.as_ptr().cast(), josievec.as_mut_ptr(), len);
+                    josievec.set_len(len);
+                }
+                josievec
+            }
+            SmallJosieVecData::Heap(josievec) => josievec,
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for SmallJosieVec<T, N> {
+    fn drop(&mut self) {
+        //the heap josievec drops its own elements, only the inline ones need dropping by hand
+        if let SmallJosieVecData::Inline { buf, len } = &mut self.data {
+            unsafe {
+                drop_in_place(slice_from_raw_parts_mut(buf.as_mut_ptr().cast::<T>(), *len));
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> Deref for SmallJosieVec<T, N> {
+    type Target = [T];
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> DerefMut for SmallJosieVec<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> Default for SmallJosieVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for SmallJosieVec<T, N> {
+    fn clone(&self) -> Self {
+        let mut temp = Self::with_capacity(self.len());
+        temp.extend(self.iter().cloned());
+        temp
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for SmallJosieVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+//small josievecs are equal if their elements are, no matter the inline capacity
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<SmallJosieVec<U, M>>
+    for SmallJosieVec<T, N>
+{
+    fn eq(&self, other: &SmallJosieVec<U, M>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<[U; M]> for SmallJosieVec<T, N> {
+    fn eq(&self, other: &[U; M]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U]> for SmallJosieVec<T, N> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for SmallJosieVec<T, N> {}
+
+impl<T, const N: usize> Extend<T> for SmallJosieVec<T, N> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iterator = iter.into_iter();
+        //reserves the lower bound up front so a long iterator spills once instead of growing on every push
+        self.reserve(iterator.size_hint().0);
+        for element in iterator {
+            self.push(element);
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for SmallJosieVec<T, N> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut temp = Self::new();
+        temp.extend(iter);
+        temp
+    }
+}
+
+//takes over the heap buffer of a josievec as an already spilled small josievec
+impl<T, const N: usize> From<JosieVec<T>> for SmallJosieVec<T, N> {
+    fn from(josievec: JosieVec<T>) -> Self {
+        Self {
+            data: SmallJosieVecData::Heap(josievec),
+        }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a SmallJosieVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut SmallJosieVec<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, const N: usize> IntoIterator for SmallJosieVec<T, N> {
+    type Item = T;
+    type IntoIter = SmallJosieVecIter<T, N>;
+    fn into_iter(self) -> Self::IntoIter {
+        //moves the storage out, the small josievec itself is never dropped so the elements are only owned once
+        let this = ManuallyDrop::new(self);
+        let iter = match unsafe { read(&this.data) } {
+            SmallJosieVecData::Inline { buf, len } => SmallJosieVecIterData::Inline {
+                buf,
+                cursor: SliceCursor::new(0..len),
+            },
+            SmallJosieVecData::Heap(josievec) => SmallJosieVecIterData::Heap(josievec.into_iter()),
+        };
+        SmallJosieVecIter { iter }
+    }
+}
+
+// ===============================
+// SMALL JOSIEVEC ITERATORS
+// -------------------------------
+
+///Owning iterator over a SmallJosieVec
+pub struct SmallJosieVecIter<T, const N: usize> {
+    iter: SmallJosieVecIterData<T, N>,
+}
+
+enum SmallJosieVecIterData<T, const N: usize> {
+    Inline {
+        buf: [MaybeUninit<T>; N],
+        cursor: SliceCursor,
+    },
+    Heap(JosieVecIter<T>),
+}
+
+impl<T, const N: usize> SmallJosieVecIter<T, N> {
+    ///Returns the elements that havent been yielded yet as a slice
+    pub fn as_slice(&self) -> &[T] {
+        match &self.iter {
+            SmallJosieVecIterData::Inline { buf, cursor } => unsafe {
+                cursor.as_slice(buf.as_ptr().cast())
+            },
+            SmallJosieVecIterData::Heap(iter) => iter.as_slice(),
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for SmallJosieVecIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        match &mut self.iter {
+            SmallJosieVecIterData::Inline { buf, cursor } => unsafe {
+                cursor.next(buf.as_ptr().cast())
+            },
+            SmallJosieVecIterData::Heap(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.as_slice().len();
+        (size, Some(size))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for SmallJosieVecIter<T, N> {
+    fn next_back(&mut self) -> Option<T> {
+        match &mut self.iter {
+            SmallJosieVecIterData::Inline { buf, cursor } => unsafe {
+                cursor.next_back(buf.as_ptr().cast())
+            },
+            SmallJosieVecIterData::Heap(iter) => iter.next_back(),
+        }
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for SmallJosieVecIter<T, N> {}
+impl<T, const N: usize> FusedIterator for SmallJosieVecIter<T, N> {}
+
+impl<T, const N: usize> Drop for SmallJosieVecIter<T, N> {
+    fn drop(&mut self) {
+        //the heap iterator drops its own elements, only the inline ones need dropping by hand
+        if let SmallJosieVecIterData::Inline { buf, cursor } = &mut self.iter {
+            unsafe { cursor.drop_remaining(buf.as_mut_ptr().cast::<T>()) }
+        }
+    }
+}
+
+///Draining iterator for SmallJosieVec, returned by SmallJosieVec::drain
+pub struct SmallJosieVecDrain<'a, T, const N: usize> {
+    drain: SliceDrain<'a, T, SmallJosieVec<T, N>>,
+}
+
+impl<T, const N: usize> SmallJosieVecDrain<'_, T, N> {
+    ///Returns the elements that havent been yielded yet as a slice
+    pub fn as_slice(&self) -> &[T] {
+        self.drain.as_slice()
+    }
+}
+
+impl<T, const N: usize> Iterator for SmallJosieVecDrain<'_, T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.drain.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drain.size_hint()
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for SmallJosieVecDrain<'_, T, N> {
+    fn next_back(&mut self) -> Option<T> {
+        self.drain.next_back()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for SmallJosieVecDrain<'_, T, N> {}
+impl<T, const N: usize> FusedIterator for SmallJosieVecDrain<'_, T, N> {}
+
+//the drained elements and the tail are moved about through the same pointer and length either way
+impl<T, const N: usize> SliceDrainSource<T> for SmallJosieVec<T, N> {
+    fn drain_ptr(&self) -> *const T {
+        self.as_ptr()
+    }
+
+    fn drain_mut_ptr(&mut self) -> *mut T {
+        self.as_mut_ptr()
+    }
+
+    fn drain_len(&self) -> usize {
+        self.len()
+    }
+
+    unsafe fn drain_set_len(&mut self, len: usize) {
+        unsafe { self.set_len(len) }
+    }
+}
@@ -1,12 +1,68 @@
 #![allow(dead_code)]
-use std::iter::repeat;
+use std::iter::repeat_n;
 use std::{any::type_name,};
 use rand::Rng;
 pub mod fake_iter_test;
 pub mod fibbonachi_test;
+pub mod try_reserve_test;
+pub mod allocator_test;
+pub mod zst_test;
+pub mod edit_test;
+pub mod drain_test;
+pub mod splice_test;
+pub mod extract_if_test;
+pub mod bulk_test;
+pub mod writer_test;
+pub mod growth_test;
+pub mod convert_test;
+pub mod traits_test;
+pub mod iter_test;
+pub mod in_place_test;
+pub mod small_josie_vec_test;
+pub mod array_josie_vec_test;
+pub mod josie_deque_test;
+pub mod josie_binary_heap_test;
+pub mod josie_string_test;
+pub mod josie_hash_map_test;
+pub mod josie_hash_set_test;
+pub mod josie_index_map_test;
+pub mod josie_index_set_test;
+pub mod josie_slab_test;
+pub mod josie_sorted_vec_test;
+pub mod josie_vec_map_test;
+pub mod josie_matrix_test;
+pub mod josie_soa_test;
 
 use crate::josievec;
 use crate::tests::fake_iter_test::{fake_iter_test, TestType};
+use crate::tests::try_reserve_test::try_reserve_test;
+use crate::tests::allocator_test::allocator_test;
+use crate::tests::zst_test::zst_test;
+use crate::tests::edit_test::edit_test;
+use crate::tests::drain_test::drain_test;
+use crate::tests::splice_test::splice_test;
+use crate::tests::extract_if_test::extract_if_test;
+use crate::tests::bulk_test::bulk_test;
+use crate::tests::writer_test::writer_test;
+use crate::tests::growth_test::growth_test;
+use crate::tests::convert_test::convert_test;
+use crate::tests::traits_test::traits_test;
+use crate::tests::iter_test::iter_test;
+use crate::tests::in_place_test::in_place_test;
+use crate::tests::small_josie_vec_test::small_josie_vec_test;
+use crate::tests::array_josie_vec_test::array_josie_vec_test;
+use crate::tests::josie_deque_test::josie_deque_test;
+use crate::tests::josie_binary_heap_test::josie_binary_heap_test;
+use crate::tests::josie_string_test::josie_string_test;
+use crate::tests::josie_hash_map_test::josie_hash_map_test;
+use crate::tests::josie_hash_set_test::josie_hash_set_test;
+use crate::tests::josie_index_map_test::josie_index_map_test;
+use crate::tests::josie_index_set_test::josie_index_set_test;
+use crate::tests::josie_slab_test::josie_slab_test;
+use crate::tests::josie_sorted_vec_test::josie_sorted_vec_test;
+use crate::tests::josie_vec_map_test::josie_vec_map_test;
+use crate::tests::josie_matrix_test::josie_matrix_test;
+use crate::tests::josie_soa_test::josie_soa_test;
 use crate::josie_vec::JosieVec;
 
 pub fn josievec_test(){
@@ -41,11 +97,67 @@ pub fn josievec_test(){
     mutate_in_place_averaging(10);
     //uses bulk guarded extend method to push fibonacci numbers to josievec directly using inline asm.
     fibonacci_test();
+    //writes through the safe writer past its reservation and fills spare capacity directly
+    writer_test();
     //creates a josievec full of clones of josievecs, mutates each josievec and then outputs the result
     mutate_nested_clones();
     //creates new josievec, drains elements from it then collects that into another  josievec. prints the original josievec before and afte
     //drain and the drained slice
     drain_then_collect();
+    //requests capacities that can never be allocated and checks they come back as errors
+    try_reserve_test();
+    //runs a josievec on a counting allocator and makes sure every allocation goes through it and is given back
+    allocator_test();
+    //runs zero sized types through push, extend, iteration and drain
+    zst_test();
+    //inserts, swap removes, retains and dedups, including predicates that panic
+    edit_test();
+    //drains with every range type, from both ends and with keep_rest
+    drain_test();
+    //splices replacements of different lengths into a josievec
+    splice_test();
+    //splits a work queue into ready and pending jobs with extract_if
+    extract_if_test();
+    //extends from slices and from within, appends and splits off
+    bulk_test();
+    //grows through each growth policy and shrinks through HalveOnQuarter
+    growth_test();
+    //moves buffers between JosieVec, Vec and Box without copying
+    convert_test();
+    //compares, hashes, orders and formats josievecs like slices
+    traits_test();
+    //walks every iterator from both ends
+    iter_test();
+    //reuses the buffer of owning iterators
+    in_place_test();
+    //spills a small josievec to the heap and back
+    small_josie_vec_test();
+    //fills a fixed capacity josievec and pushes past it
+    array_josie_vec_test();
+    //pushes to both ends of a deque and grows it while wrapped
+    josie_deque_test();
+    //pushes random priorities through heaps of different arity
+    josie_binary_heap_test();
+    //edits a utf-8 string on char boundaries
+    josie_string_test();
+    //checks the hash map against std with random inserts and removes
+    josie_hash_map_test();
+    //runs the set operations over two hash sets
+    josie_hash_set_test();
+    //removes from an insertion ordered map every way it can
+    josie_index_map_test();
+    //keeps the first time each word shows up
+    josie_index_set_test();
+    //spawns entities that follow each other and compacts them
+    josie_slab_test();
+    //keeps version numbers sorted and looks up ranges of them
+    josie_sorted_vec_test();
+    //counts letters in a sorted map
+    josie_vec_map_test();
+    //runs a glider across a grid and reshapes a matrix of strings
+    josie_matrix_test();
+    //stores planets one column per field and sorts them
+    josie_soa_test();
 }
 
 fn init_test_vec<T>()->JosieVec<T>{
@@ -135,7 +247,7 @@ fn mutate_in_place_averaging(max:usize){
 
 pub fn macro_test(){
     println!("Creating a Josievec filled with JosieVecs. tests macro functionality and into_iter\n\n");
-    let iterate = josievec!(from repeat("from iter directly").take(2));
+    let iterate = josievec!(from repeat_n("from iter directly", 2));
     let josievec_of_josievecs = josievec![
         josievec!["with commas", "all of these", "have been ", "macroed"],
         josievec!["elem;num style";3],
@@ -162,6 +274,13 @@ fn fibonacci_test(){
     println!("popped one {:?}", fibonacchi_push.pop());
     println!("removed element 3 {:?}", fibonacchi_push.remove(3));
     println!("remaining elements are {:?}", fibonacchi_push.as_slice());
+    //safe writer version has to produce the same numbers as the asm version
+    let mut fibonacci_safe:JosieVec<usize> = JosieVec::new();
+    fibonacci_safe.fibonacci_push_safe(15);
+    let mut fibonacci_asm:JosieVec<usize> = JosieVec::new();
+    fibonacci_asm.fibonacci_push(15);
+    println!("safe writer fibonacci push {:?}", fibonacci_safe.as_slice());
+    assert_eq!(fibonacci_safe.as_slice(), fibonacci_asm.as_slice());
 }
 
 fn mutate_nested_clones(){
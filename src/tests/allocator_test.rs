@@ -0,0 +1,51 @@
+use std::{alloc::Layout, cell::Cell, ptr::NonNull};
+
+use crate::josie_vec::{josievec_alloc::{Global, JosieAllocator}, JosieVec};
+
+///Allocator that forwards to the global allocator and keeps a running count of live bytes, the way you would attribute memory per tenant
+#[derive(Default)]
+pub struct CountingAllocator{
+    pub(crate) live_bytes:Cell<usize>,
+    pub(crate) allocations:Cell<usize>,
+}
+
+unsafe impl JosieAllocator for CountingAllocator{
+    fn allocate(&self, layout:Layout) -> Option<NonNull<u8>>{
+        self.live_bytes.set(self.live_bytes.get() + layout.size());
+        self.allocations.set(self.allocations.get() + 1);
+        Global.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr:NonNull<u8>, layout:Layout){
+        self.live_bytes.set(self.live_bytes.get() - layout.size());
+        unsafe{Global.deallocate(ptr, layout)}
+    }
+
+    unsafe fn reallocate(&self, ptr:NonNull<u8>, old_layout:Layout, new_layout:Layout) -> Option<NonNull<u8>>{
+        self.live_bytes.set(self.live_bytes.get() - old_layout.size() + new_layout.size());
+        unsafe{Global.reallocate(ptr, old_layout, new_layout)}
+    }
+}
+
+///Runs a josievec through growth, shrinking, clone, drain and into_iter on a counting allocator and checks every byte is given back
+pub fn allocator_test(){
+    println!("\nAllocator test will run a JosieVec on a counting allocator and check all memory is returned\n\n");
+    let counter = CountingAllocator::default();
+    {
+        let mut josievec = JosieVec::new_in(&counter);
+        for i in 0..10u64{
+            josievec.push(i);
+        }
+        println!("After 10 pushes live bytes are {}, capacity is {}", counter.live_bytes.get(), josievec.capacity());
+        assert_eq!(counter.live_bytes.get(), josievec.capacity() * size_of::<u64>());
+        let clone = josievec.clone();
+        println!("After clone live bytes are {}", counter.live_bytes.get());
+        josievec.shrink_to_fit();
+        let drained = josievec.drain(0..3).count();
+        println!("Drained {drained} elements, live bytes are {}", counter.live_bytes.get());
+        let summed:u64 = clone.into_iter().sum();
+        println!("Sum of clone through into_iter is {summed}, live bytes are {}", counter.live_bytes.get());
+    }
+    assert_eq!(counter.live_bytes.get(), 0);
+    println!("All {} allocations were returned to the counting allocator", counter.allocations.get());
+}
@@ -0,0 +1,58 @@
+use std::{panic::{catch_unwind, AssertUnwindSafe}, rc::Rc};
+
+use crate::{array_josie_vec::ArrayJosieVec, array_josievec};
+
+///Fills an ArrayJosieVec to capacity, checks pushing past it fails, and runs extend through a panicking iterator
+pub fn array_josie_vec_test(){
+    println!("\nArray josievec test will fill a fixed capacity josievec and check it never goes past its capacity\n\n");
+    let mut array:ArrayJosieVec<String, 4> = array_josievec![String::from("a"), String::from("b")];
+    array.push(String::from("c"));
+    array.insert(0, String::from("start"));
+    assert!(array.is_full());
+    println!("Full array josievec {array:?}");
+    assert_eq!(array.try_push(String::from("d")), Err(String::from("d")));
+    assert_eq!(array.try_insert(1, String::from("e")), Err(String::from("e")));
+    assert_eq!(array.remove(1), "a");
+    assert_eq!(array.pop().as_deref(), Some("c"));
+    assert_eq!(array.swap_remove(0), "start");
+    assert_eq!(array, ["b"]);
+    assert_eq!(array.remaining_capacity(), 3);
+
+    let mut array:ArrayJosieVec<i32, 8> = array_josievec!(from 0..6);
+    let drained:Vec<i32> = array.drain(1..=2).rev().collect();
+    assert_eq!(drained, [2, 1]);
+    assert_eq!(array, [0, 3, 4, 5]);
+    array.extend_from_slice(&[6, 7]);
+    let reversed:Vec<i32> = array.clone().into_iter().rev().collect();
+    println!("Reversed by value {reversed:?}");
+    assert_eq!(reversed, [7, 6, 5, 4, 3, 0]);
+    let array:ArrayJosieVec<i32, 3> = array_josievec![9; 3];
+    assert_eq!(array.into_inner(), Ok([9, 9, 9]));
+
+    //a panic part way through extend keeps every element written so far and drops them all once
+    let counter = Rc::new(());
+    let mut array:ArrayJosieVec<Rc<()>, 8> = ArrayJosieVec::new();
+    let result = catch_unwind(AssertUnwindSafe(||{
+        array.extend((0..6).map(|i|{
+            if i == 4{
+                panic!("Boom! Panic!");
+            }
+            counter.clone()
+        }));
+    }));
+    assert!(result.is_err());
+    assert_eq!(array.len(), 4);
+    assert_eq!(Rc::strong_count(&counter), 5);
+
+    //extending past the capacity panics but keeps the elements that fit
+    let result = catch_unwind(AssertUnwindSafe(||{
+        array.extend((0..6).map(|_| counter.clone()));
+    }));
+    assert!(result.is_err());
+    assert!(array.is_full());
+    let mut iter = array.into_iter();
+    iter.next();
+    drop(iter);
+    assert_eq!(Rc::strong_count(&counter), 1);
+    println!("Overfilled extend panicked and every element was dropped exactly once");
+}
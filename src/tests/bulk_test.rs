@@ -0,0 +1,33 @@
+use crate::{josie_vec::JosieVec, josievec};
+
+///Extends from slices and from within, appends and splits off, checking contents and that other is left empty
+pub fn bulk_test(){
+    println!("\nBulk test will extend from slices, append and split off josievecs\n\n");
+    let mut josievec:JosieVec<String> = JosieVec::new();
+    let words = ["bulk".to_string(), "slice".to_string()];
+    josievec.extend_from_slice(&words);
+    josievec.extend_from_within(..);
+    josievec.extend_from_within(1..=2);
+    println!("After cloning extends {:?}", josievec.as_slice());
+    assert_eq!(josievec.as_slice(), &["bulk", "slice", "bulk", "slice", "slice", "bulk"]);
+
+    let mut numbers:JosieVec<u32> = JosieVec::new();
+    numbers.extend_from_copy_slice(&[1, 2, 3]);
+    numbers.extend_from_within(1..);
+    assert_eq!(numbers.as_slice(), &[1, 2, 3, 2, 3]);
+
+    let mut other:JosieVec<u32> = josievec![7, 8, 9];
+    numbers.append(&mut other);
+    println!("After append {:?}, other has length {} and capacity {}", numbers.as_slice(), other.len(), other.capacity());
+    assert_eq!(numbers.as_slice(), &[1, 2, 3, 2, 3, 7, 8, 9]);
+    assert!(other.is_empty());
+
+    let tail = josievec.split_off(4);
+    println!("After split off {:?} and {:?}", josievec.as_slice(), tail.as_slice());
+    assert_eq!(josievec.as_slice(), &["bulk", "slice", "bulk", "slice"]);
+    assert_eq!(tail.as_slice(), &["slice", "bulk"]);
+    assert!(josievec.split_off(4).is_empty());
+    let all = josievec.split_off(0);
+    assert!(josievec.is_empty());
+    assert_eq!(all.len(), 4);
+}
@@ -0,0 +1,54 @@
+use crate::{josie_vec::JosieVec, josievec};
+
+///Moves buffers between JosieVec, Vec and Box<[T]> checking that the pointer never changes, then tests the
+///raw parts api and the copying conversions
+pub fn convert_test(){
+    println!("\nConvert test will move buffers between JosieVec, Vec and Box<[T]> and check no copies were made\n\n");
+    let mut vec = Vec::with_capacity(10);
+    vec.extend(["a", "b", "c"].map(String::from));
+    let ptr = vec.as_ptr();
+    let josievec = JosieVec::from(vec);
+    assert_eq!(josievec.as_ptr(), ptr);
+    assert_eq!(josievec.capacity(), 10);
+    println!("Vec moved into JosieVec {:?} with capacity {}", josievec.as_slice(), josievec.capacity());
+    let vec:Vec<String> = josievec.into();
+    assert_eq!(vec.as_ptr(), ptr);
+    assert_eq!(vec.capacity(), 10);
+
+    //into_boxed_slice has to shrink first so the capacity box frees matches the allocation
+    let mut josievec = JosieVec::from(vec);
+    josievec.push("d".to_string());
+    let boxed:Box<[String]> = josievec.into();
+    println!("JosieVec moved into Box {boxed:?}");
+    assert_eq!(boxed.len(), 4);
+    let ptr = boxed.as_ptr();
+    let josievec = JosieVec::from(boxed);
+    assert_eq!(josievec.as_ptr(), ptr);
+    assert_eq!(josievec.capacity(), 4);
+    let boxed = josievec![1u8; 0].into_boxed_slice();
+    assert!(boxed.is_empty());
+
+    //round trip through raw parts
+    let josievec = josievec![1, 2, 3];
+    let (ptr, len, cap) = josievec.into_raw_parts();
+    let josievec = unsafe{JosieVec::from_raw_parts(ptr, len, cap)};
+    assert_eq!(josievec.as_slice(), &[1, 2, 3]);
+
+    let cap = josievec.capacity();
+    let leaked:&'static mut [i32] = josievec.leak();
+    leaked[0] = 10;
+    println!("Leaked slice {leaked:?}");
+    //takes the leaked allocation back so it isnt reported as a leak
+    let josievec = unsafe{JosieVec::from_raw_parts(leaked.as_mut_ptr(), 3, cap)};
+    assert_eq!(josievec.as_slice(), &[10, 2, 3]);
+
+    let josievec = JosieVec::from([String::from("x"), String::from("y")]);
+    assert_eq!(josievec.as_slice(), &["x", "y"]);
+    let josievec = JosieVec::from(&[1, 2, 3][..]);
+    assert_eq!(josievec.as_slice(), &[1, 2, 3]);
+
+    //zero sized types only ever carry a length across
+    let vec:Vec<()> = JosieVec::from(vec![(); 5]).into();
+    assert_eq!(vec.len(), 5);
+    println!("All conversions kept their contents");
+}
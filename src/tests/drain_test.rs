@@ -0,0 +1,61 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::{josie_vec::JosieVec, josievec};
+
+//element that panics when dropped if its value is the poison value, used to check the tail survives a panicking drop
+pub(crate) struct PanicOnDrop(pub(crate) i32);
+
+impl Drop for PanicOnDrop{
+    fn drop(&mut self){
+        if self.0 == 3 && !std::thread::panicking(){
+            panic!("Boom! Panic!");
+        }
+    }
+}
+
+///Drains with every kind of range, from both ends, with keep_rest, and with a drop that panics
+pub fn drain_test(){
+    println!("\nDrain test will drain josievecs with different range types and check the tail is put back\n\n");
+    let mut josievec:JosieVec<i32> = josievec![0, 1, 2, 3, 4, 5, 6, 7];
+    let drained:JosieVec<i32> = josievec.drain(5..).collect();
+    println!("Drained 5.. to the end {:?}, left with {:?}", drained.as_slice(), josievec.as_slice());
+    assert_eq!(drained.as_slice(), &[5, 6, 7]);
+    assert_eq!(josievec.as_slice(), &[0, 1, 2, 3, 4]);
+    let drained:JosieVec<i32> = josievec.drain(..=1).rev().collect();
+    assert_eq!(drained.as_slice(), &[1, 0]);
+    assert_eq!(josievec.as_slice(), &[2, 3, 4]);
+    josievec.extend([5, 6, 7]);
+
+    //takes one from each end, looks at what is left then keeps it
+    let mut drain = josievec.drain(1..5);
+    assert_eq!(drain.next(), Some(3));
+    assert_eq!(drain.next_back(), Some(6));
+    assert_eq!(drain.as_slice(), &[4, 5]);
+    assert_eq!(drain.size_hint(), (2, Some(2)));
+    drain.keep_rest();
+    println!("After keep_rest {:?}", josievec.as_slice());
+    assert_eq!(josievec.as_slice(), &[2, 4, 5, 7]);
+
+    drop(josievec.drain(1..3));
+    assert_eq!(josievec.as_slice(), &[2, 7]);
+    assert_eq!(josievec.drain(..).count(), 2);
+    assert!(josievec.is_empty());
+
+    //leaking a drain leaks the drained elements and the tail but leaves the josievec valid
+    let mut josievec:JosieVec<i32> = josievec![0, 1, 2, 3];
+    std::mem::forget(josievec.drain(1..2));
+    assert_eq!(josievec.as_slice(), &[0]);
+
+    //dropping an element in the drain panics, the tail still has to be moved back
+    let mut josievec:JosieVec<PanicOnDrop> = (0..6).map(PanicOnDrop).collect();
+    let _ = catch_unwind(AssertUnwindSafe(||{
+        drop(josievec.drain(2..4));
+    }));
+    let values:JosieVec<i32> = josievec.iter().map(|element| element.0).collect();
+    println!("After panicking drop in drain {:?}", values.as_slice());
+    assert_eq!(values.as_slice(), &[0, 1, 4, 5]);
+
+    let mut zst:JosieVec<()> = josievec![(); 10];
+    assert_eq!(zst.drain(2..=4).rev().count(), 3);
+    assert_eq!(zst.len(), 7);
+}
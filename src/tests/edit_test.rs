@@ -0,0 +1,63 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::{josie_vec::JosieVec, josievec};
+
+///Runs insert, swap_remove, retain and the dedup family, including predicates that panic halfway through
+pub fn edit_test(){
+    println!("\nEdit test will insert, swap remove, retain and dedup elements in place\n\n");
+    let mut josievec:JosieVec<i32> = josievec![1, 2, 4, 5];
+    josievec.insert(2, 3);
+    josievec.insert(0, 0);
+    josievec.insert(josievec.len(), 6);
+    println!("After inserts {:?}", josievec.as_slice());
+    assert_eq!(josievec.as_slice(), &[0, 1, 2, 3, 4, 5, 6]);
+    assert_eq!(josievec.swap_remove(1), 1);
+    assert_eq!(josievec.swap_remove(josievec.len() - 1), 5);
+    println!("After swap removes {:?}", josievec.as_slice());
+    assert_eq!(josievec.as_slice(), &[0, 6, 2, 3, 4]);
+
+    josievec.retain(|element| element % 2 == 0);
+    println!("After retaining even numbers {:?}", josievec.as_slice());
+    assert_eq!(josievec.as_slice(), &[0, 6, 2, 4]);
+    josievec.retain_mut(|element|{
+        *element *= 10;
+        *element != 60
+    });
+    assert_eq!(josievec.as_slice(), &[0, 20, 40]);
+
+    let mut josievec:JosieVec<String> = ["a", "a", "b", "b", "b", "a", "c", "c"].into_iter().map(String::from).collect();
+    josievec.dedup();
+    println!("After dedup {:?}", josievec.as_slice());
+    assert_eq!(josievec.as_slice(), &["a", "b", "a", "c"]);
+    let mut josievec:JosieVec<i32> = josievec![10, 11, 20, 21, 22, 30];
+    josievec.dedup_by_key(|element| *element / 10);
+    assert_eq!(josievec.as_slice(), &[10, 20, 30]);
+
+    //predicate panics on the fourth element, everything not yet checked has to be kept
+    let mut josievec:JosieVec<String> = (0..8).map(|i| i.to_string()).collect();
+    let mut calls = 0;
+    let _ = catch_unwind(AssertUnwindSafe(||{
+        josievec.retain(|element|{
+            calls += 1;
+            if calls == 4{
+                panic!("Boom! Panic!");
+            }
+            element != "1"
+        });
+    }));
+    println!("After panicking retain {:?}", josievec.as_slice());
+    assert_eq!(josievec.as_slice(), &["0", "2", "3", "4", "5", "6", "7"]);
+
+    //same_bucket panics partway through a dedup
+    let mut josievec:JosieVec<String> = ["x", "x", "y", "y", "z"].into_iter().map(String::from).collect();
+    let _ = catch_unwind(AssertUnwindSafe(||{
+        josievec.dedup_by(|a, _|{
+            if a == "z"{
+                panic!("Boom! Panic!");
+            }
+            true
+        });
+    }));
+    println!("After panicking dedup {:?}", josievec.as_slice());
+    assert_eq!(josievec.as_slice(), &["x", "z"]);
+}
@@ -0,0 +1,46 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::{josie_vec::JosieVec, josievec};
+
+//job in a work queue, ready jobs are split off from pending ones
+#[derive(Debug)]
+struct Job{
+    id:u32,
+    ready:bool,
+}
+
+///Splits a work queue into ready and pending jobs with extract_if, then checks early drops and panics leave it valid
+pub fn extract_if_test(){
+    println!("\nExtract if test will split a work queue into ready and pending jobs\n\n");
+    let mut queue:JosieVec<Job> = (0..10).map(|id| Job{id, ready:id % 3 == 0}).collect();
+    let ready:JosieVec<Job> = queue.extract_if(.., |job| job.ready).collect();
+    let ready_ids:JosieVec<u32> = ready.iter().map(|job| job.id).collect();
+    let pending_ids:JosieVec<u32> = queue.iter().map(|job| job.id).collect();
+    println!("Ready jobs {:?}, pending jobs {:?}", ready_ids.as_slice(), pending_ids.as_slice());
+    assert_eq!(ready_ids.as_slice(), &[0, 3, 6, 9]);
+    assert_eq!(pending_ids.as_slice(), &[1, 2, 4, 5, 7, 8]);
+
+    //only extracts within the range, and stops after the first match
+    let mut josievec:JosieVec<i32> = josievec![1, 2, 3, 4, 5, 6, 7, 8];
+    let first_even = josievec.extract_if(2..6, |element| *element % 2 == 0).next();
+    assert_eq!(first_even, Some(4));
+    println!("After extracting one even from 2..6 {:?}", josievec.as_slice());
+    assert_eq!(josievec.as_slice(), &[1, 2, 3, 5, 6, 7, 8]);
+
+    //predicate panics on the fifth element checked, everything unchecked stays in place
+    let mut josievec:JosieVec<String> = (0..8).map(|i| i.to_string()).collect();
+    let mut extracted = JosieVec::new();
+    let _ = catch_unwind(AssertUnwindSafe(||{
+        for element in josievec.extract_if(.., |element|{
+            if element == "4"{
+                panic!("Boom! Panic!");
+            }
+            element == "1" || element == "2"
+        }){
+            extracted.push(element);
+        }
+    }));
+    println!("After panicking extract if {:?}, extracted {:?}", josievec.as_slice(), extracted.as_slice());
+    assert_eq!(josievec.as_slice(), &["0", "3", "4", "5", "6", "7"]);
+    assert_eq!(extracted.as_slice(), &["1", "2"]);
+}
@@ -4,7 +4,7 @@ use crate::{
     josie_vec::JosieVec};
 
 
-pub fn fake_iter_test<T: IntoIterator, A>(test_type:TestType, elements:T)
+pub fn fake_iter_test<T, A>(test_type:TestType, elements:T)
     where T: IntoIterator<Item = A>, A:Debug
     {
         let test_type @ 
@@ -2,6 +2,17 @@ use std::{arch::asm};
 
 use crate::josie_vec::{josievec_extend::ExtendType, *};
 impl JosieVec<usize>{
+    ///Safe version of fibonacci_push. The writer owns the write pointer and keeps the length the same way the raii
+    ///guard does, so the hot loop needs no unsafe at all.
+    pub fn fibonacci_push_safe(&mut self, elems:usize){
+        let mut writer = self.writer(ExtendType::Exact(elems));
+        let (mut a, mut b) = (0, 1);
+        for _ in 0..elems{
+            writer.push(a);
+            (a, b) = (b, a + b);
+        }
+    }
+
     ///Tests Bulk extend guarded function. fibonacci push just calculates a fibonacci numbers and uses the ptr given by the closure to write to the buffer directly.
     ///the only bookkepeing for how many elements written is the state of the pointer itself, the raii guard calculates length on its drop. additionally,
     ///hot loops like this where you want to avoid calculating pointer offsets alltogether and just push in bulk is the perfect use for this function.
@@ -0,0 +1,68 @@
+use crate::josie_vec::{
+    josievec_growth::{Doubling, FixedIncrement, HalveOnQuarter, OneAndAHalf, PageRounded},
+    JosieVec,
+};
+
+///Pushes through each growth policy printing the capacities it picks, then pops through a shrinking policy
+pub fn growth_test(){
+    println!("\nGrowth test will push through each growth policy and print the capacity after every push\n\n");
+    let mut josievec = JosieVec::with_policy(OneAndAHalf);
+    let mut capacities = Vec::new();
+    for i in 0..10{
+        josievec.push(i);
+        capacities.push(josievec.capacity());
+    }
+    println!("One and a half growth capacities {capacities:?}");
+    assert_eq!(capacities, [1, 2, 3, 4, 6, 6, 9, 9, 9, 13]);
+
+    let mut josievec = JosieVec::with_policy(FixedIncrement(4));
+    for i in 0..9{
+        josievec.push(i);
+    }
+    println!("Fixed increment of 4 grew to capacity {} for 9 elements", josievec.capacity());
+    assert_eq!(josievec.capacity(), 12);
+    //reserve rounds up to whole steps as well
+    josievec.reserve(10);
+    assert_eq!(josievec.capacity(), 20);
+
+    //a 64 byte page fits 8 u64s so the first push already gets a whole page
+    let mut josievec = JosieVec::with_policy(PageRounded{page_size:64});
+    josievec.push(1u64);
+    println!("Page rounded growth gave capacity {} for one u64 in 64 byte pages", josievec.capacity());
+    assert_eq!(josievec.capacity(), 8);
+    josievec.extend(0..8);
+    assert_eq!(josievec.capacity(), 16);
+
+    println!("\nPopping through HalveOnQuarter, capacity should halve once length drops below a quarter of it\n");
+    let mut josievec = JosieVec::with_policy(HalveOnQuarter(Doubling));
+    josievec.extend(0..64);
+    assert_eq!(josievec.capacity(), 64);
+    while josievec.len() > 16{
+        josievec.pop();
+    }
+    //exactly a quarter is not enough to shrink
+    assert_eq!(josievec.capacity(), 64);
+    josievec.pop();
+    println!("Length {} capacity {}", josievec.len(), josievec.capacity());
+    assert_eq!(josievec.capacity(), 32);
+    josievec.truncate(2);
+    println!("Truncated to length {} capacity {}", josievec.len(), josievec.capacity());
+    assert_eq!(josievec.capacity(), 16);
+    assert_eq!(josievec.as_slice(), &[0, 1]);
+    josievec.retain(|x| *x == 0);
+    josievec.clear();
+    println!("Cleared, capacity {}", josievec.capacity());
+    assert!(josievec.capacity() < 16);
+    //keep_rest removes the yielded elements for good so it shrinks as well
+    let mut josievec = JosieVec::with_policy(HalveOnQuarter(Doubling));
+    josievec.extend(0..64);
+    let mut drain = josievec.drain(..);
+    drain.by_ref().take(56).for_each(drop);
+    drain.keep_rest();
+    println!("Kept the rest of a drain, length {} capacity {}", josievec.len(), josievec.capacity());
+    assert_eq!(josievec.as_slice(), &[56, 57, 58, 59, 60, 61, 62, 63]);
+    assert_eq!(josievec.capacity(), 32);
+    //clones keep the policy
+    let cloned = josievec.clone();
+    assert_eq!(cloned.policy(), josievec.policy());
+}
@@ -0,0 +1,70 @@
+use std::{panic::{catch_unwind, AssertUnwindSafe}, rc::Rc};
+
+use crate::{josie_vec::{josievec_alloc::Global, josievec_growth::FixedIncrement, JosieVec}, josievec, tests::allocator_test::CountingAllocator};
+
+///Turns partly consumed owning iterators back into josievecs and maps them in place, checking the buffer is reused
+pub fn in_place_test(){
+    println!("\nIn place test will reuse the buffer of owning iterators and check no allocation is made\n\n");
+    let mut josievec = JosieVec::with_capacity(16);
+    josievec.extend((0..8).map(|i| i.to_string()));
+    let ptr = josievec.as_ptr();
+    let mut iter = josievec.into_iter();
+    iter.next();
+    iter.next_back();
+    let josievec = iter.into_josievec();
+    println!("Remainder {josievec:?} kept capacity {}", josievec.capacity());
+    assert_eq!(josievec, ["1", "2", "3", "4", "5", "6"]);
+    assert_eq!(josievec.as_ptr(), ptr);
+    assert_eq!(josievec.capacity(), 16);
+
+    //the growth policy survives the round trip and both kinds of map
+    let mut josievec = JosieVec::with_policy(FixedIncrement(3));
+    josievec.extend([1u32, 2, 3]);
+    let mut josievec = josievec.into_iter().into_josievec();
+    josievec.push(4);
+    assert_eq!((josievec.policy(), josievec.capacity()), (&FixedIncrement(3), 6));
+    let halves = josievec.into_iter().map_in_place(|x| x as f32 / 2.0);
+    assert_eq!(halves.policy(), &FixedIncrement(3));
+    let bytes = halves.into_iter().map_in_place(|x| x as u8);
+    assert_eq!((bytes.policy(), bytes.as_slice()), (&FixedIncrement(3), &[0u8, 1, 1, 2][..]));
+
+    //u32 to f32 has the same layout so the buffer is reused
+    let alloc = CountingAllocator::default();
+    let mut josievec = JosieVec::with_capacity_in(4, &alloc);
+    josievec.extend([1u32, 2, 3, 4]);
+    let ptr = josievec.as_ptr() as usize;
+    let mut iter = josievec.into_iter();
+    iter.next();
+    let floats = iter.map_in_place(|x| x as f32 / 2.0);
+    println!("Mapped in place {floats:?}");
+    assert_eq!(floats, [1.0, 1.5, 2.0]);
+    assert_eq!(floats.as_ptr() as usize, ptr);
+    assert_eq!(alloc.allocations.get(), 1);
+    drop(floats);
+    assert_eq!(alloc.live_bytes.get(), 0);
+
+    //a different layout falls back to a new buffer
+    let josievec = josievec![1u8, 2, 3];
+    let wide = josievec.into_iter().map_in_place(u64::from);
+    assert_eq!(wide, [1u64, 2, 3]);
+
+    //a panicking map drops the results written so far, the unread elements and the buffer
+    let counter = Rc::new(());
+    let josievec:JosieVec<Rc<()>, Global> = (0..6).map(|_| counter.clone()).collect();
+    let result = catch_unwind(AssertUnwindSafe(||{
+        let mut calls = 0;
+        josievec.into_iter().map_in_place(|rc|{
+            calls += 1;
+            if calls == 4{
+                panic!("Boom! Panic!");
+            }
+            Some(rc)
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(Rc::strong_count(&counter), 1);
+    println!("Panicking map dropped everything exactly once");
+
+    let units = JosieVec::from([(); 3]).into_iter().map_in_place(|()| ((), ()));
+    assert_eq!(units.len(), 3);
+}
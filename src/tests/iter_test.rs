@@ -0,0 +1,71 @@
+use std::rc::Rc;
+
+use crate::{josie_vec::JosieVec, josievec};
+
+///Walks every josievec iterator from both ends, skips with nth and nth_back, and checks len, zip and clone
+pub fn iter_test(){
+    println!("\nIter test will walk every josievec iterator from both ends and check the remaining counts\n\n");
+    let mut josievec = josievec![0, 1, 2, 3, 4, 5];
+
+    let mut iter = josievec.iter();
+    assert_eq!(iter.len(), 6);
+    assert_eq!(iter.next_back(), Some(&5));
+    assert_eq!(iter.nth(1), Some(&1));
+    assert_eq!(iter.nth_back(1), Some(&3));
+    assert_eq!(iter.as_slice(), &[2]);
+    let cloned = iter.clone();
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.nth(5), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(cloned.len(), 1);
+    let reversed:Vec<_> = josievec.iter().rev().copied().collect();
+    println!("Reversed {reversed:?}");
+    assert_eq!(reversed, [5, 4, 3, 2, 1, 0]);
+
+    for (element, offset) in josievec.iter_mut().rev().zip(10..){
+        *element += offset;
+    }
+    println!("Added offsets from the back {josievec:?}");
+    assert_eq!(josievec, [15, 15, 15, 15, 15, 15]);
+    let mut iter = josievec.iter_mut();
+    *iter.nth_back(2).unwrap() = 0;
+    assert_eq!(iter.len(), 3);
+    assert_eq!(josievec, [15, 15, 15, 0, 15, 15]);
+
+    //the owning iterator drops everything it skips exactly once
+    let counter = Rc::new(());
+    let josievec:JosieVec<Rc<()>> = (0..8).map(|_| counter.clone()).collect();
+    let mut iter = josievec.into_iter();
+    assert_eq!(iter.size_hint(), (8, Some(8)));
+    drop(iter.next());
+    assert_eq!(iter.len(), 7);
+    assert!(iter.nth(2).is_some());
+    assert!(iter.nth_back(1).is_some());
+    assert_eq!(iter.len(), 2);
+    assert_eq!(Rc::strong_count(&counter), 1 + 2);
+    let cloned = iter.clone();
+    assert_eq!(Rc::strong_count(&counter), 1 + 2 + 2);
+    drop(iter);
+    drop(cloned);
+    assert_eq!(Rc::strong_count(&counter), 1);
+    println!("Owning iterator dropped every skipped and unyielded element");
+
+    let josievec = josievec![String::from("a"), String::from("b"), String::from("c")];
+    let mut iter = josievec.into_iter();
+    assert_eq!(iter.next_back().as_deref(), Some("c"));
+    iter.as_mut_slice()[0].push('!');
+    assert_eq!(iter.as_slice(), &["a!", "b"]);
+    let zipped:Vec<_> = iter.zip(1..).collect();
+    assert_eq!(zipped, [(String::from("a!"), 1), (String::from("b"), 2)]);
+
+    let mut josievec = josievec![0, 1, 2, 3, 4, 5, 6];
+    let mut drain = josievec.drain(1..6);
+    assert_eq!(drain.len(), 5);
+    assert_eq!(drain.nth(1), Some(2));
+    assert_eq!(drain.nth_back(1), Some(4));
+    assert_eq!(drain.len(), 1);
+    assert_eq!(drain.nth(3), None);
+    assert_eq!(drain.next(), None);
+    drop(drain);
+    assert_eq!(josievec, [0, 6]);
+}
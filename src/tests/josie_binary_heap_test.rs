@@ -0,0 +1,57 @@
+use std::cmp::Reverse;
+
+use rand::Rng;
+
+use crate::{josie_binary_heap::{JosieBinaryHeap, JosieBinaryHeapPeekMut}, josie_vec::JosieVec, josievec};
+
+//pops every element and checks they come out greatest first
+fn check_pops<const ARITY:usize>(mut heap:JosieBinaryHeap<i32, ARITY>, mut expected:Vec<i32>){
+    expected.sort_unstable_by(|a, b| b.cmp(a));
+    let popped:Vec<i32> = heap.drain_sorted().collect();
+    assert_eq!(popped, expected);
+}
+
+///Schedules random priorities through binary, 4-ary and 8-ary heaps and checks they come out in order
+pub fn josie_binary_heap_test(){
+    println!("\nJosie binary heap test will push random priorities through heaps of different arity\n\n");
+    let mut rng = rand::rng();
+    let values:Vec<i32> = (0..200).map(|_| rng.random_range(-1000..1000)).collect();
+    let mut binary:JosieBinaryHeap<i32> = JosieBinaryHeap::new();
+    for &value in &values{
+        binary.push(value);
+    }
+    check_pops(binary, values.clone());
+    check_pops::<4>(values.iter().copied().collect(), values.clone());
+    check_pops::<8>(JosieBinaryHeap::from(JosieVec::from(values.clone())), values.clone());
+
+    let heap:JosieBinaryHeap<i32, 4> = values.iter().copied().collect();
+    let sorted = heap.into_sorted_vec();
+    assert!(sorted.is_sorted());
+    println!("4-ary heap sorted {} elements, smallest {:?} largest {:?}", sorted.len(), sorted.first(), sorted.last());
+
+    //peek_mut sifts the root back down only if it was changed
+    let mut heap:JosieBinaryHeap<i32> = JosieBinaryHeap::from(josievec![5, 1, 8, 3]);
+    assert_eq!(heap.peek(), Some(&8));
+    if let Some(mut top) = heap.peek_mut(){
+        *top = 0;
+    }
+    assert_eq!(heap.peek(), Some(&5));
+    let top = heap.peek_mut().unwrap();
+    assert_eq!(JosieBinaryHeapPeekMut::pop(top), 5);
+    assert_eq!(heap.len(), 3);
+
+    //append and retain keep the heap in order
+    let mut other:JosieBinaryHeap<i32> = (10..20).collect();
+    heap.append(&mut other);
+    assert!(other.is_empty());
+    heap.retain(|x| x % 2 == 0);
+    println!("After append and retain {heap:?}");
+    assert_eq!(heap.into_sorted_vec(), [0, 10, 12, 14, 16, 18]);
+
+    //reverse gives a min heap, a half used drain_sorted drops the rest
+    let mut jobs:JosieBinaryHeap<Reverse<(u8, String)>, 8> = JosieBinaryHeap::new();
+    jobs.extend([(3, "write"), (1, "read"), (2, "parse")].map(|(priority, job)| Reverse((priority, job.to_string()))));
+    let first = jobs.drain_sorted().next().map(|Reverse((_, job))| job);
+    assert_eq!(first.as_deref(), Some("read"));
+    assert!(jobs.is_empty());
+}
@@ -0,0 +1,75 @@
+use std::rc::Rc;
+
+use crate::{josie_deque::JosieDeque, josie_vec::JosieVec, josievec};
+
+///Wraps a JosieDeque round the end of its buffer, grows it while wrapped, and converts it to and from a JosieVec
+pub fn josie_deque_test(){
+    println!("\nJosie deque test will push to both ends, wrap the ring and grow it while wrapped\n\n");
+    let mut deque = JosieDeque::with_capacity(4);
+    deque.push_back(2);
+    deque.push_back(3);
+    deque.push_front(1);
+    deque.push_front(0);
+    let (first, second) = deque.as_slices();
+    println!("Wrapped ring {first:?} {second:?} with capacity {}", deque.capacity());
+    assert_eq!((first, second), (&[0, 1][..], &[2, 3][..]));
+    //grows while wrapped, the order has to survive the realloc
+    deque.push_back(4);
+    deque.push_front(-1);
+    println!("After growing {deque:?} with capacity {}", deque.capacity());
+    assert_eq!(deque, [-1, 0, 1, 2, 3, 4]);
+    assert_eq!(deque.capacity(), 8);
+    assert_eq!(deque[1], 0);
+    deque[5] = 40;
+    assert_eq!(deque.back(), Some(&40));
+
+    //used as a fifo queue the ring keeps wrapping without growing
+    for i in 0..20{
+        deque.push_back(i);
+        deque.pop_front();
+    }
+    assert_eq!(deque.capacity(), 8);
+    assert_eq!(deque, [14, 15, 16, 17, 18, 19]);
+    assert_eq!(deque.iter().rev().copied().collect::<Vec<_>>(), [19, 18, 17, 16, 15, 14]);
+    for element in deque.iter_mut(){
+        *element -= 10;
+    }
+    assert_eq!(deque.make_contiguous(), &[4, 5, 6, 7, 8, 9]);
+    assert_eq!(deque.as_slices().1, &[]);
+
+    let drained:Vec<i32> = deque.drain(1..3).collect();
+    assert_eq!(drained, [5, 6]);
+    assert_eq!(deque, [4, 7, 8, 9]);
+    assert_eq!(deque.pop_back(), Some(9));
+    assert_eq!(deque.pop_front(), Some(4));
+
+    //converting to a josievec lines the ring up at the front of the same buffer
+    let mut deque:JosieDeque<i32> = JosieDeque::from(josievec![1, 2, 3]);
+    deque.push_front(0);
+    let josievec:JosieVec<i32> = deque.into();
+    println!("Back into a josievec {josievec:?}");
+    assert_eq!(josievec, [0, 1, 2, 3]);
+
+    //every element is dropped exactly once no matter where the ring wraps
+    let counter = Rc::new(());
+    let mut deque = JosieDeque::new();
+    for _ in 0..5{
+        deque.push_front(counter.clone());
+        deque.push_back(counter.clone());
+    }
+    let mut iter = deque.clone().into_iter();
+    iter.next();
+    iter.next_back();
+    drop(iter);
+    deque.drain(2..4).next();
+    deque.truncate(5);
+    drop(deque);
+    assert_eq!(Rc::strong_count(&counter), 1);
+
+    let mut units = JosieDeque::new();
+    units.push_front(());
+    units.push_back(());
+    assert_eq!(units.len(), 2);
+    assert_eq!(units.into_iter().count(), 2);
+    println!("Every element was dropped exactly once");
+}
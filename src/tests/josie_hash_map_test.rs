@@ -0,0 +1,81 @@
+use std::{collections::HashMap, hash::{BuildHasherDefault, Hasher}, rc::Rc};
+
+use rand::Rng;
+
+use crate::{josie_hash_map::{JosieHashMap, JosieHashMapEntry}, josie_vec::josievec_error::JosieTryReserveError};
+
+//hasher that sends every key to the same bucket so every lookup has to probe past tombstones
+#[derive(Default)]
+struct CollidingHasher;
+
+impl Hasher for CollidingHasher{
+    fn finish(&self) -> u64{
+        7
+    }
+    fn write(&mut self, _bytes:&[u8]){}
+}
+
+///Inserts and removes random keys next to a std HashMap, then counts words with the entry api
+pub fn josie_hash_map_test(){
+    println!("\nJosie hash map test will insert and remove random keys next to a std hash map\n\n");
+    let mut rng = rand::rng();
+    let mut josie:JosieHashMap<u16, u32> = JosieHashMap::new();
+    let mut std_map:HashMap<u16, u32> = HashMap::new();
+    for step in 0..5000{
+        let key = rng.random_range(0..500);
+        if rng.random_bool(0.6){
+            assert_eq!(josie.insert(key, step), std_map.insert(key, step));
+        }else{
+            assert_eq!(josie.remove(&key), std_map.remove(&key));
+        }
+        assert_eq!(josie.len(), std_map.len());
+    }
+    assert!(std_map.iter().all(|(key, value)| josie[key] == *value));
+    println!("{} keys left after 5000 random inserts and removes, capacity {}", josie.len(), josie.capacity());
+
+    //entry api
+    let mut counts:JosieHashMap<&str, usize> = JosieHashMap::new();
+    for word in "the cat and the dog and the bird".split(' '){
+        *counts.entry(word).or_default() += 1;
+    }
+    assert_eq!(counts.get("the"), Some(&3));
+    assert_eq!(counts["and"], 2);
+    counts.entry("cat").and_modify(|count| *count += 10).or_insert(0);
+    if let JosieHashMapEntry::Occupied(entry) = counts.entry("dog"){
+        assert_eq!(entry.remove_entry(), ("dog", 1));
+    }
+    assert_eq!(*counts.entry("fish").or_insert_with_key(|key| key.len()), 4);
+    println!("word counts {counts:?}");
+
+    //every key in one probe chain, removing from the middle has to leave the rest reachable
+    let mut colliding:JosieHashMap<i32, i32, BuildHasherDefault<CollidingHasher>> = JosieHashMap::default();
+    colliding.extend((0..40).map(|x| (x, x * x)));
+    colliding.retain(|key, value| {*value += 1; key % 3 != 0});
+    assert_eq!(colliding.len(), 26);
+    assert!((0..40).all(|x| colliding.get(&x).copied() == if x % 3 == 0 {None} else {Some(x * x + 1)}));
+
+    //drain keeps the allocation, dropping a half used drain drops the rest
+    let shared = Rc::new(());
+    let mut owners:JosieHashMap<i32, Rc<()>> = (0..10).map(|x| (x, shared.clone())).collect();
+    let capacity = owners.capacity();
+    assert_eq!(owners.drain().take(3).count(), 3);
+    assert!(owners.is_empty());
+    assert_eq!(owners.capacity(), capacity);
+    assert_eq!(Rc::strong_count(&shared), 1);
+
+    //shrinking, cloning and comparing
+    josie.retain(|key, _| *key < 10);
+    josie.shrink_to_fit();
+    let copy = josie.clone();
+    assert_eq!(copy, josie);
+    assert!(josie.capacity() >= josie.len() && josie.capacity() < 16);
+    let mut sorted:Vec<(u16, u32)> = josie.into_iter().collect();
+    sorted.sort_unstable();
+    println!("after shrinking {sorted:?}");
+
+    //try_reserve hands back an error instead of panicking
+    let mut map:JosieHashMap<u64, u64> = JosieHashMap::from([(1, 2)]);
+    assert_eq!(map.try_reserve(usize::MAX), Err(JosieTryReserveError::CapacityOverflow));
+    assert_eq!(map.get(&1), Some(&2));
+    assert!(map.try_reserve(100).is_ok() && map.capacity() >= 101);
+}
@@ -0,0 +1,43 @@
+use crate::josie_hash_set::JosieHashSet;
+
+//collects a set iterator into a sorted vec so it can be compared
+fn sorted<'a>(iter:impl Iterator<Item = &'a i32>) -> Vec<i32>{
+    let mut elements:Vec<i32> = iter.copied().collect();
+    elements.sort_unstable();
+    elements
+}
+
+///Runs the set operations over multiples of two and three
+pub fn josie_hash_set_test(){
+    println!("\nJosie hash set test will compare multiples of two and three\n\n");
+    let twos:JosieHashSet<i32> = (0..20).step_by(2).collect();
+    let threes:JosieHashSet<i32> = (0..20).step_by(3).collect();
+    assert_eq!(sorted(twos.intersection(&threes)), [0, 6, 12, 18]);
+    assert_eq!(sorted(twos.difference(&threes)), [2, 4, 8, 10, 14, 16]);
+    assert_eq!(sorted(twos.symmetric_difference(&threes)), [2, 3, 4, 8, 9, 10, 14, 15, 16]);
+    assert_eq!(twos.union(&threes).count(), 13);
+    println!("multiples of six {:?}", sorted(twos.intersection(&threes)));
+
+    let mut small = JosieHashSet::from([6, 12]);
+    assert!(small.is_subset(&twos) && small.is_subset(&threes) && threes.is_superset(&small));
+    assert!(!small.insert(6));
+    assert!(small.insert(7));
+    assert!(!small.is_subset(&twos));
+    assert!(small.remove(&7) && !small.contains(&7));
+    assert!(JosieHashSet::from([1, 5]).is_disjoint(&twos));
+
+    //replace hands back the stored element, take removes it
+    let mut names:JosieHashSet<String> = ["ada", "alan"].map(String::from).into();
+    assert_eq!(names.replace("ada".to_string()).as_deref(), Some("ada"));
+    assert_eq!(names.get("alan").map(String::as_str), Some("alan"));
+    assert_eq!(names.take("alan").as_deref(), Some("alan"));
+    assert_eq!(names.len(), 1);
+
+    let mut evens = twos.clone();
+    evens.retain(|x| x % 4 == 0);
+    evens.extend(&[2, 6]);
+    assert_eq!(evens, JosieHashSet::from([0, 2, 4, 6, 8, 12, 16]));
+    println!("after retain and extend {evens:?}");
+    assert_eq!(evens.drain().count(), 7);
+    assert!(evens.is_empty() && evens.try_reserve(10).is_ok());
+}
@@ -0,0 +1,77 @@
+use std::{mem::forget, panic::{catch_unwind, AssertUnwindSafe}};
+
+use crate::{josie_index_map::JosieIndexMap, tests::drain_test::PanicOnDrop};
+
+//keys of the map in order, to check the order is what it should be
+fn keys(map:&JosieIndexMap<String, u32>) -> Vec<&str>{
+    map.keys().map(String::as_str).collect()
+}
+
+///Builds a config in insertion order and checks every kind of removal keeps the index in sync
+pub fn josie_index_map_test(){
+    println!("\nJosie index map test will build a config and remove from it every way it can\n\n");
+    let mut config:JosieIndexMap<String, u32> = JosieIndexMap::new();
+    for (key, value) in [("width", 80), ("height", 24), ("depth", 3), ("tabs", 4), ("margin", 2), ("wrap", 1)]{
+        config.insert(key.to_string(), value);
+    }
+    //updating a key keeps its place
+    assert_eq!(config.insert("height".to_string(), 25), Some(24));
+    assert_eq!(keys(&config), ["width", "height", "depth", "tabs", "margin", "wrap"]);
+    assert_eq!(config.get_index(1), Some((&"height".to_string(), &25)));
+    assert_eq!(config.get_index_of("tabs"), Some(3));
+    assert_eq!(config["margin"], 2);
+    config[0] += 1;
+    println!("config {config:?}");
+
+    //swap_remove moves the last entry into the hole, shift_remove keeps the order
+    assert_eq!(config.swap_remove("height"), Some(25));
+    assert_eq!(keys(&config), ["width", "wrap", "depth", "tabs", "margin"]);
+    assert_eq!(config.shift_remove_full("depth"), Some((2, "depth".to_string(), 3)));
+    assert_eq!(keys(&config), ["width", "wrap", "tabs", "margin"]);
+    //every remaining key still points at the right place
+    assert!(config.iter().enumerate().all(|(position, (key, _))| config.get_index_of(key) == Some(position)));
+    assert_eq!(config.pop(), Some(("margin".to_string(), 2)));
+    assert_eq!(config.get("margin"), None);
+
+    //grows past a few rehashes, then drains from the middle
+    let mut numbers:JosieIndexMap<u32, u32> = (0..100).map(|x| (x, x * 10)).collect();
+    let drained:Vec<u32> = numbers.drain(10..90).map(|(key, _)| key).collect();
+    assert_eq!(drained, (10..90).collect::<Vec<u32>>());
+    assert_eq!(numbers.len(), 20);
+    assert_eq!(numbers.get_index_of(&95), Some(15));
+    assert_eq!(numbers.get(&50), None);
+    numbers.retain(|key, value| {*value += 1; key % 2 == 0});
+    assert_eq!(numbers.values().copied().collect::<Vec<u32>>(), [1, 21, 41, 61, 81, 901, 921, 941, 961, 981]);
+
+    //sorting rebuilds the index to the new positions
+    let mut scores:JosieIndexMap<&str, i32> = JosieIndexMap::from([("cara", 7), ("abe", 9), ("bo", 3)]);
+    scores.sort_keys();
+    assert_eq!(scores.keys().copied().collect::<Vec<&str>>(), ["abe", "bo", "cara"]);
+    assert_eq!(scores.get_full("cara"), Some((2, &"cara", &7)));
+    scores.sort_by(|_, a, _, b| b.cmp(a));
+    assert_eq!(scores.iter().next_back(), Some((&"bo", &3)));
+    assert_eq!(scores.get_index_of("abe"), Some(0));
+    let copy = scores.clone();
+    assert_eq!(copy, JosieIndexMap::from([("bo", 3), ("cara", 7), ("abe", 9)]));
+    println!("scores sorted by value {scores:?}");
+
+    //a predicate or comparison that panics halfway and a leaked drain all leave the index in sync
+    let mut panicky:JosieIndexMap<u32, u32> = (0..10).map(|x| (x, x)).collect();
+    let _ = catch_unwind(AssertUnwindSafe(|| panicky.retain(|key, _| if *key == 6 {panic!("Boom! Panic!")} else {key % 2 == 0})));
+    let _ = catch_unwind(AssertUnwindSafe(|| panicky.sort_by(|a, _, b, _| if *a == 8 || *b == 8 {panic!("Boom! Panic!")} else {b.cmp(a)})));
+    forget(panicky.drain(1..2));
+    assert!(panicky.iter().enumerate().all(|(position, (key, _))| panicky.get_index_of(key) == Some(position)));
+    //leaking the drain leaks the tail too, so only the first entry is left
+    assert_eq!(panicky.len(), 1);
+    for key in [2, 4, 2, 4]{
+        panicky.insert(key, 99);
+    }
+    assert_eq!(panicky.len(), 3);
+    println!("map after panics and a leaked drain {panicky:?}");
+
+    //a drained value that panics when dropped still leaves the tail hashed back in at its new positions
+    let mut poisoned:JosieIndexMap<i32, PanicOnDrop> = (0..8).map(|x| (x, PanicOnDrop(x))).collect();
+    let _ = catch_unwind(AssertUnwindSafe(|| drop(poisoned.drain(2..5))));
+    assert_eq!(poisoned.keys().copied().collect::<Vec<i32>>(), [0, 1, 5, 6, 7]);
+    assert!(poisoned.keys().enumerate().all(|(position, key)| poisoned.get_index_of(key) == Some(position)));
+}
@@ -0,0 +1,29 @@
+use crate::josie_index_set::JosieIndexSet;
+
+///Keeps the first time each word shows up, in order
+pub fn josie_index_set_test(){
+    println!("\nJosie index set test will keep the first time each word shows up\n\n");
+    let text = "one fish two fish red fish blue fish";
+    let mut words:JosieIndexSet<&str> = text.split(' ').collect();
+    assert_eq!(words.iter().copied().collect::<Vec<&str>>(), ["one", "fish", "two", "red", "blue"]);
+    assert_eq!(words.insert_full("red"), (3, false));
+    assert_eq!(words.insert_full("green"), (5, true));
+    assert_eq!(words[1], "fish");
+    assert_eq!(words.get_index_of("blue"), Some(4));
+
+    assert!(words.shift_remove("two"));
+    assert_eq!(words.swap_remove_index(0), Some("one"));
+    assert_eq!(words.iter().copied().collect::<Vec<&str>>(), ["green", "fish", "red", "blue"]);
+    words.sort();
+    assert_eq!(words.first(), Some(&"blue"));
+    assert_eq!(words.last(), Some(&"red"));
+    println!("sorted words {words:?}");
+
+    let drained:Vec<&str> = words.drain(1..3).collect();
+    assert_eq!(drained, ["fish", "green"]);
+    assert_eq!(words, JosieIndexSet::from(["red", "blue"]));
+    assert!(words.contains("red") && !words.contains("fish"));
+    words.extend(&["a", "b", "red"]);
+    words.retain(|word| word.len() > 1);
+    assert_eq!(words.into_iter().collect::<Vec<&str>>(), ["blue", "red"]);
+}
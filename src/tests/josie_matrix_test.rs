@@ -0,0 +1,72 @@
+use crate::josie_matrix::JosieMatrix;
+
+//one step of the game of life on a grid that wraps around at the edges
+fn life_step(grid:&JosieMatrix<bool>) -> JosieMatrix<bool>{
+    let (rows, cols) = grid.shape();
+    JosieMatrix::from_fn(rows, cols, |r, c|{
+        let mut neighbours = 0;
+        for dr in [rows - 1, 0, 1]{
+            for dc in [cols - 1, 0, 1]{
+                if (dr, dc) != (0, 0) && grid[((r + dr) % rows, (c + dc) % cols)]{
+                    neighbours += 1;
+                }
+            }
+        }
+        neighbours == 3 || (neighbours == 2 && grid[(r, c)])
+    })
+}
+
+///Runs a glider across a grid then reshapes, transposes and edits a matrix of strings
+pub fn josie_matrix_test(){
+    println!("\nJosie matrix test will run a glider across a grid and then reshape a matrix of names\n\n");
+    let mut grid = JosieMatrix::from_elem(6, 6, false);
+    for (r, c) in [(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)]{
+        grid[(r, c)] = true;
+    }
+    let start = grid.clone();
+    //a glider moves one cell down and one right every 4 steps, so after 24 it is back where it started
+    for step in 1..=24{
+        grid = life_step(&grid);
+        if step == 4{
+            assert!(grid[(3, 2)] && grid[(3, 3)] && grid[(3, 1)] && !grid[(0, 1)]);
+        }
+    }
+    assert_eq!(grid, start);
+    for row in grid.iter_rows(){
+        println!("{}", row.iter().map(|alive| if *alive {'#'} else {'.'}).collect::<String>());
+    }
+    assert_eq!(grid.iter_columns().map(|column| column.iter().filter(|alive| **alive).count()).collect::<Vec<usize>>(), [1, 2, 2, 0, 0, 0]);
+
+    //names are not copy so every move below has to hand each one over exactly once
+    let mut names = JosieMatrix::from_fn(3, 4, |r, c| format!("{r}{c}"));
+    assert_eq!(names.row(1), ["10", "11", "12", "13"]);
+    assert_eq!(names.column(2).iter().map(String::as_str).collect::<Vec<&str>>(), ["02", "12", "22"]);
+    names.row_mut(0)[0].push('!');
+    for name in names.column_mut(3){
+        name.push('?');
+    }
+    names.insert_column(1, ["a", "b", "c"].map(String::from));
+    assert_eq!(names.shape(), (3, 5));
+    assert_eq!(names.row(2), ["20", "c", "21", "22", "23?"]);
+    names.push_row(["x", "y", "z", "w", "v"].map(String::from));
+    assert_eq!(names.pop_row().map(|row| row.len()), Some(5));
+    println!("names {names:?}");
+
+    let flipped = names.clone().transpose();
+    assert_eq!(flipped.shape(), (5, 3));
+    assert!((0..3).all(|r| (0..5).all(|c| names[(r, c)] == flipped[(c, r)])));
+    assert_eq!(flipped.column(0).get(0).map(String::as_str), Some("00!"));
+
+    //grows and shrinks both ways, keeping whatever is still inside
+    names.resize(4, 2, "-".to_string());
+    assert_eq!(names.as_slice(), ["00!", "a", "10", "b", "20", "c", "-", "-"]);
+    names.resize(2, 2, String::new());
+    names.resize(3, 2, "+".to_string());
+    assert_eq!(names, JosieMatrix::from([["00!", "a"], ["10", "b"], ["+", "+"]].map(|row| row.map(String::from))));
+
+    //a matrix bigger than one transpose tile in both directions, with ragged edges
+    let big = JosieMatrix::from_fn(37, 21, |r, c| r * 100 + c);
+    let big_t = big.clone().transpose().transpose();
+    assert_eq!(big, big_t);
+    assert_eq!(big.transpose()[(20, 36)], 3620);
+}
@@ -0,0 +1,71 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::josie_slab::{JosieSlab, JosieSlabKey};
+
+///Tracks entities that point at each other by key, kills some and compacts the rest
+pub fn josie_slab_test(){
+    println!("\nJosie slab test will spawn entities that follow each other and kill some of them\n\n");
+    let mut entities:JosieSlab<(String, Option<JosieSlabKey>)> = JosieSlab::with_capacity(4);
+    let names = ["ant", "bee", "cat", "dog", "eel", "fox"];
+    let mut keys = Vec::new();
+    let mut last = None;
+    for name in names{
+        let key = entities.insert((name.to_string(), last));
+        keys.push(key);
+        last = Some(key);
+    }
+    assert_eq!(entities.len(), 6);
+    assert_eq!(entities[keys[3]].0, "dog");
+    assert_eq!(entities[keys[3]].1, Some(keys[2]));
+
+    //a removed key stops matching even after its slot is handed out again
+    assert_eq!(entities.remove(keys[1]).map(|(name, _)| name), Some("bee".to_string()));
+    assert!(entities.remove(keys[1]).is_none());
+    let gnu = entities.insert_with_key(|key| (format!("gnu{}", key.index()), Some(key)));
+    assert_eq!(gnu.index(), keys[1].index());
+    assert_ne!(gnu.generation(), keys[1].generation());
+    assert!(entities.get(keys[1]).is_none() && !entities.contains(keys[1]));
+    assert_eq!(entities[gnu], ("gnu1".to_string(), Some(gnu)));
+
+    entities.retain(|_, (name, _)| !name.starts_with('c') && !name.starts_with('d'));
+    assert_eq!(entities.len(), 4);
+    for (_, (name, _)) in entities.iter_mut(){
+        name.make_ascii_uppercase();
+    }
+    println!("entities {entities:?}");
+
+    //eel and fox move into the holes cat and dog left, so everything following them is pointed at the new keys
+    let mut moves = Vec::new();
+    entities.compact(|_, old, new| moves.push((old, new)));
+    assert_eq!(moves.len(), 2);
+    for (_, (_, follows)) in entities.iter_mut(){
+        if let Some(&(_, new)) = moves.iter().find(|(old, _)| Some(*old) == *follows){
+            *follows = Some(new);
+        }
+    }
+    assert_eq!(entities.capacity(), 4);
+    assert!(keys[4..].iter().all(|key| !entities.contains(*key)));
+    let fox = moves.iter().find(|(old, _)| *old == keys[5]).unwrap().1;
+    let eel = moves.iter().find(|(old, _)| *old == keys[4]).unwrap().1;
+    assert_eq!(entities[fox], ("FOX".to_string(), Some(eel)));
+
+    //a slot dropped by compact never matches its old keys when it grows back
+    let newt = entities.insert(("NEWT".to_string(), None));
+    let owl = entities.insert(("OWL".to_string(), None));
+    assert_eq!(owl.index(), keys[5].index());
+    assert!(!entities.contains(keys[5]) && entities.contains(newt));
+
+    let names:Vec<String> = entities.into_iter().rev().map(|(_, (name, _))| name).collect();
+    assert_eq!(names, ["OWL", "NEWT", "EEL", "FOX", "GNU1", "ANT"]);
+
+    //a remap that panics leaves the slot it moved out of behind, and moving into it later never revives the old key
+    let mut slab:JosieSlab<u32> = JosieSlab::new();
+    let keys:Vec<JosieSlabKey> = (0..3).map(|x| slab.insert(x)).collect();
+    slab.remove(keys[0]);
+    let _ = catch_unwind(AssertUnwindSafe(|| slab.compact(|_, _, _| panic!("Boom! Panic!"))));
+    let late = slab.insert(3);
+    assert_eq!(late.index(), 3);
+    slab.compact(|_, old, new| assert!(old == late && new.index() == keys[2].index()));
+    assert!(!slab.contains(keys[2]));
+    assert_eq!(slab.len(), 3);
+}
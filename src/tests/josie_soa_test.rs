@@ -0,0 +1,55 @@
+use crate::josie_soa;
+
+josie_soa!{
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Planet{
+        pub name: String,
+        pub mass: f64,
+        pub moons: u32,
+    }
+    #[derive(Debug, Clone)]
+    pub struct PlanetSoA;
+    #[derive(Debug)]
+    pub struct PlanetRef;
+    pub struct PlanetRefMut;
+}
+
+fn planet(name:&str, mass:f64, moons:u32) -> Planet{
+    Planet{name:name.to_string(), mass, moons}
+}
+
+///Keeps the planets one column per field and sorts them with every column moving together
+pub fn josie_soa_test(){
+    println!("\nJosie soa test will store the planets one column per field and sort them\n\n");
+    let mut planets:PlanetSoA = [planet("mercury", 0.055, 0), planet("venus", 0.815, 0), planet("earth", 1.0, 1), planet("mars", 0.107, 2)].into_iter().collect();
+    planets.extend([planet("jupiter", 317.8, 95), planet("saturn", 95.2, 146)]);
+    assert_eq!(planets.len(), 6);
+    assert_eq!(planets.moons(), &[0, 0, 1, 2, 95, 146]);
+    assert_eq!(planets.mass().iter().sum::<f64>().round(), 415.0);
+    let earth = planets.get(2).unwrap();
+    assert_eq!((earth.name.as_str(), *earth.moons), ("earth", 1));
+    assert!(planets.get(6).is_none());
+
+    //every column is permuted the same way so each row still belongs together
+    planets.sort_by_key(|planet| *planet.moons);
+    assert_eq!(planets.moons(), &[0, 0, 1, 2, 95, 146]);
+    planets.sort_by_key(|planet| planet.name.clone());
+    assert_eq!(planets.name(), ["earth", "jupiter", "mars", "mercury", "saturn", "venus"]);
+    assert_eq!(planets.moons(), &[1, 95, 2, 0, 146, 0]);
+    println!("planets {planets:?}");
+
+    for planet in planets.iter_mut().rev().take(2){
+        *planet.moons += 1;
+    }
+    if let Some(mars) = planets.get_mut(2){
+        mars.name.make_ascii_uppercase();
+    }
+    assert_eq!(planets.swap_remove(1), planet("jupiter", 317.8, 95));
+    assert_eq!(planets.pop(), Some(planet("saturn", 95.2, 147)));
+    let moons:Vec<(&str, u32)> = planets.iter().map(|planet| (planet.name.as_str(), *planet.moons)).collect();
+    assert_eq!(moons, [("earth", 1), ("venus", 1), ("MARS", 2), ("mercury", 0)]);
+    let copy = planets.clone();
+    planets.truncate(1);
+    assert_eq!(copy.len(), 4);
+    assert_eq!((&planets).into_iter().next_back().map(|planet| planet.name.clone()), Some("earth".to_string()));
+}
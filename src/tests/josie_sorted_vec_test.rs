@@ -0,0 +1,39 @@
+use std::ops::Bound;
+use crate::{josie_sorted_vec::JosieSortedVec, josievec};
+
+///Keeps a sorted list of version numbers and looks ranges of them up
+pub fn josie_sorted_vec_test(){
+    println!("\nJosie sorted vec test will keep version numbers sorted and look up ranges of them\n\n");
+    let mut versions = JosieSortedVec::from_unsorted(josievec![(1, 4), (0, 9), (1, 2), (0, 9), (2, 0), (1, 2)]);
+    assert_eq!(versions.as_slice(), &[(0, 9), (1, 2), (1, 4), (2, 0)]);
+    assert!(versions.insert((1, 3)));
+    assert!(!versions.insert((2, 0)));
+    assert_eq!(versions.get_index_of(&(1, 3)), Some(2));
+    assert_eq!(versions.search(&(1, 5)), Err(4));
+    assert!(versions.contains(&(0, 9)) && !versions.contains(&(3, 0)));
+
+    //every 1.x release, then everything from 1.3 up to but not including 2.0
+    assert_eq!(versions.range((1, 0)..(2, 0)), &[(1, 2), (1, 3), (1, 4)]);
+    assert_eq!(versions.range((1, 3)..), &[(1, 3), (1, 4), (2, 0)]);
+    assert_eq!(versions.range(..=(1, 2)), &[(0, 9), (1, 2)]);
+    assert!(versions.range((2, 0)..(1, 0)).is_empty());
+    println!("versions {versions:?}");
+
+    assert!(versions.remove(&(1, 3)));
+    assert_eq!(versions.take(&(1, 3)), None);
+    assert_eq!(versions.pop_first(), Some((0, 9)));
+    assert_eq!(versions.pop_last(), Some((2, 0)));
+    versions.extend([(3, 1), (1, 2), (0, 1), (3, 0)]);
+    assert_eq!(versions.as_slice(), &[(0, 1), (1, 2), (1, 4), (3, 0), (3, 1)]);
+    versions.retain(|(major, _)| *major != 1);
+    //the slice methods come through deref
+    assert_eq!(versions.first(), Some(&(0, 1)));
+    assert!(versions.windows(2).all(|pair| pair[0] < pair[1]));
+    let latest:Vec<(u32, u32)> = versions.drain(1..).collect();
+    assert_eq!(latest, [(3, 0), (3, 1)]);
+
+    let words:JosieSortedVec<String> = ["pear", "apple", "fig", "apple"].into_iter().map(String::from).collect();
+    assert!(words.contains("fig"));
+    assert_eq!(words.range::<str, _>((Bound::Included("b"), Bound::Excluded("g"))), ["fig"]);
+    assert_eq!(words.into_iter().collect::<Vec<String>>(), ["apple", "fig", "pear"]);
+}
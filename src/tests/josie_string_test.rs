@@ -0,0 +1,67 @@
+use std::fmt::Write;
+
+use crate::{josie_string::JosieString, josie_vec::JosieVec, josievec};
+
+///Builds strings out of multi byte chars and checks every edit lands on a char boundary
+pub fn josie_string_test(){
+    println!("\nJosie string test will edit a string full of multi byte chars\n\n");
+    let mut string = JosieString::new();
+    string.push_str("héllo");
+    string.push(' ');
+    string.push('🦀');
+    assert_eq!(string, "héllo 🦀");
+    assert_eq!(string.len(), "héllo 🦀".len());
+
+    string.insert_str(0, "¡");
+    string.insert(string.len(), '!');
+    assert_eq!(string, "¡héllo 🦀!");
+    assert_eq!(string.remove(0), '¡');
+    assert_eq!(string.remove(1), 'é');
+    assert_eq!(string.pop(), Some('!'));
+    assert_eq!(string, "hllo 🦀");
+    println!("after edits {string:?}");
+
+    //cutting into the middle of the crab has to panic
+    let crab_start = string.find('🦀').unwrap();
+    let mut cut = string.clone();
+    assert!(std::panic::catch_unwind(move || cut.truncate(crab_start + 1)).is_err());
+    let mut cut = string.clone();
+    assert!(std::panic::catch_unwind(move || cut.insert_str(crab_start + 2, "x")).is_err());
+    string.truncate(crab_start);
+    assert_eq!(string, "hllo ");
+
+    //deref gives every str method for free
+    assert!(string.starts_with("hl"));
+    string.make_ascii_uppercase();
+    assert_eq!(string.trim_end(), "HLLO");
+
+    write!(string, "{}-{:02}", 7, 3).unwrap();
+    assert_eq!(string, "HLLO 7-03");
+
+    let collected:JosieString = "abç".chars().rev().collect();
+    assert_eq!(collected, "çba");
+    let joined:JosieString = ["one", "two", "three"].into_iter().collect();
+    assert_eq!(joined, "onetwothree");
+
+    //valid bytes are taken over without copying, invalid ones come back in the error
+    let bytes:JosieVec<u8> = josievec![0xf0, 0x9f, 0xa6, 0x80];
+    let ptr = bytes.as_ptr();
+    let crab = JosieString::from_utf8(bytes).unwrap();
+    assert_eq!(crab, "🦀");
+    assert_eq!(crab.as_ptr(), ptr);
+    let error = JosieString::from_utf8(josievec![b'o', b'k', 0xff]).unwrap_err();
+    assert_eq!(error.utf8_error().valid_up_to(), 2);
+    println!("invalid bytes gave error: {error}");
+    assert_eq!(error.into_bytes(), [b'o', b'k', 0xff]);
+
+    //round trips through String and JosieVec<u8> keep the same buffer
+    let std_string = String::from("round trip ✓");
+    let ptr = std_string.as_ptr();
+    let josie = JosieString::from(std_string);
+    assert_eq!(josie.as_ptr(), ptr);
+    let back:String = josie.clone().into();
+    assert_eq!(back, "round trip ✓");
+    let bytes:JosieVec<u8> = josie.into();
+    assert_eq!(bytes.as_ptr(), ptr);
+    println!("round tripped {:?}", String::from(JosieString::try_from(bytes).unwrap()));
+}
@@ -0,0 +1,42 @@
+use crate::josie_vec_map::{JosieVecMap, JosieVecMapEntry};
+
+///Counts the letters of a sentence with the entry api and reads the counts back in order
+pub fn josie_vec_map_test(){
+    println!("\nJosie vec map test will count the letters of a sentence in a sorted map\n\n");
+    let mut counts:JosieVecMap<char, usize> = JosieVecMap::new();
+    for letter in "the quick brown fox".chars().filter(|letter| *letter != ' '){
+        *counts.entry(letter).or_default() += 1;
+    }
+    assert_eq!(counts.len(), 15);
+    assert_eq!(counts.first_key_value(), Some((&'b', &1)));
+    assert_eq!(counts.last_key_value(), Some((&'x', &1)));
+    assert_eq!(counts[&'o'], 2);
+    assert!(counts.keys().zip(counts.keys().skip(1)).all(|(a, b)| a < b));
+    assert_eq!(counts.range('q'..='u').map(|(letter, _)| *letter).collect::<String>(), "qrtu");
+    println!("letter counts {counts:?}");
+
+    match counts.entry('z'){
+        JosieVecMapEntry::Vacant(entry) => {
+            assert_eq!(entry.index(), 15);
+            entry.insert(0);
+        }
+        JosieVecMapEntry::Occupied(_) => unreachable!("z isnt in the sentence"),
+    }
+    counts.entry('u').and_modify(|count| *count += 10).or_insert(0);
+    assert_eq!(counts.get(&'u'), Some(&11));
+    assert_eq!(counts.insert('o', 5), Some(2));
+    assert_eq!(counts.remove(&'z'), Some(0));
+    assert_eq!(counts.remove_entry(&'z'), None);
+    counts.retain(|_, count| *count > 1);
+    assert_eq!(counts.iter().collect::<Vec<(&char, &usize)>>(), [(&'o', &5), (&'u', &11)]);
+
+    //later values win when the same key is there more than once
+    let mut settings = JosieVecMap::from([("zoom", 1), ("alpha", 2), ("zoom", 3)]);
+    settings.extend([("beta", 4), ("alpha", 5)]);
+    assert_eq!(settings, JosieVecMap::from([("alpha", 5), ("beta", 4), ("zoom", 3)]));
+    for value in settings.values_mut(){
+        *value *= 10;
+    }
+    assert_eq!(settings.pop_first(), Some(("alpha", 50)));
+    assert_eq!(settings.into_iter().rev().collect::<Vec<(&str, i32)>>(), [("zoom", 30), ("beta", 40)]);
+}
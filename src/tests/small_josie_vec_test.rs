@@ -0,0 +1,68 @@
+use std::rc::Rc;
+
+use crate::small_josie_vec::SmallJosieVec;
+
+///Fills a SmallJosieVec past its inline capacity, drains and pops it back down, then moves it back inline
+pub fn small_josie_vec_test(){
+    println!("\nSmall josievec test will push past the inline capacity and check it spills and comes back\n\n");
+    let mut small:SmallJosieVec<String, 4> = SmallJosieVec::new();
+    assert_eq!(small.capacity(), 4);
+    for i in 0..4{
+        small.push(i.to_string());
+    }
+    assert!(!small.spilled());
+    println!("Inline {small:?} with capacity {}", small.capacity());
+    small.push(String::from("4"));
+    assert!(small.spilled());
+    println!("Spilled {small:?} with capacity {}", small.capacity());
+    assert_eq!(small.capacity(), 8);
+
+    small.insert(0, String::from("start"));
+    assert_eq!(small.remove(1), "0");
+    let drained:Vec<String> = small.drain(1..3).collect();
+    assert_eq!(drained, ["1", "2"]);
+    assert_eq!(small, ["start", "3", "4"]);
+    small.shrink_to_fit();
+    assert!(!small.spilled());
+    println!("Shrunk back inline {small:?}");
+    assert_eq!(small.pop().as_deref(), Some("4"));
+    assert_eq!(small.swap_remove(0), "start");
+    assert_eq!(small, ["3"]);
+
+    //drains work the same inline
+    let mut small:SmallJosieVec<i32, 8> = (0..6).collect();
+    let mut drain = small.drain(1..5);
+    assert_eq!(drain.next_back(), Some(4));
+    assert_eq!(drain.as_slice(), &[1, 2, 3]);
+    drop(drain);
+    assert_eq!(small, [0, 5]);
+    let sorted = {
+        small.extend([3, 1]);
+        small.sort();
+        small.clone()
+    };
+    assert_eq!(sorted, [0, 1, 3, 5]);
+
+    //every element is dropped exactly once whether it was inline, spilled, iterated or drained
+    let counter = Rc::new(());
+    for count in [2, 3, 6]{
+        let small:SmallJosieVec<Rc<()>, 3> = (0..count).map(|_| counter.clone()).collect();
+        let mut iter = small.clone().into_iter();
+        iter.next();
+        iter.next_back();
+        drop(iter);
+        let mut small = small;
+        small.drain(..1);
+        small.truncate(1);
+        assert_eq!(small.into_josievec().len(), 1);
+    }
+    assert_eq!(Rc::strong_count(&counter), 1);
+
+    let units:SmallJosieVec<(), 2> = std::iter::repeat_n((), 5).collect();
+    assert!(units.spilled());
+    assert_eq!(units.into_iter().count(), 5);
+    let mut empty:SmallJosieVec<u8, 0> = SmallJosieVec::new();
+    empty.push(1);
+    assert!(empty.spilled());
+    println!("Every element was dropped exactly once");
+}
@@ -0,0 +1,40 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::{josie_vec::JosieVec, josievec};
+
+///Splices shorter, longer, exact size and unknown size replacements into a josievec
+pub fn splice_test(){
+    println!("\nSplice test will replace windows of a josievec with iterators of different lengths\n\n");
+    let mut josievec:JosieVec<i32> = josievec![0, 1, 2, 3, 4, 5];
+    //longer exact size replacement, the tail is moved once
+    let removed:JosieVec<i32> = josievec.splice(1..3, [10, 11, 12, 13]).collect();
+    println!("Removed {:?}, josievec is now {:?}", removed.as_slice(), josievec.as_slice());
+    assert_eq!(removed.as_slice(), &[1, 2]);
+    assert_eq!(josievec.as_slice(), &[0, 10, 11, 12, 13, 3, 4, 5]);
+    //shorter replacement
+    drop(josievec.splice(1..5, [1]));
+    assert_eq!(josievec.as_slice(), &[0, 1, 3, 4, 5]);
+    //replacement with no upper bound that is longer than the gap
+    drop(josievec.splice(2..3, (2..=3).filter(|_| true)));
+    assert_eq!(josievec.as_slice(), &[0, 1, 2, 3, 4, 5]);
+    //replacement with a lower bound that under reports
+    drop(josievec.splice(..1, [7, 8, 9].into_iter().chain((0..2).filter(|_| true))));
+    assert_eq!(josievec.as_slice(), &[7, 8, 9, 0, 1, 1, 2, 3, 4, 5]);
+    //splicing to the end just extends
+    drop(josievec.splice(3.., 100..103));
+    println!("After splices {:?}", josievec.as_slice());
+    assert_eq!(josievec.as_slice(), &[7, 8, 9, 100, 101, 102]);
+
+    //replacement iterator panics halfway through, the tail is still moved back
+    let mut josievec:JosieVec<String> = (0..5).map(|i| i.to_string()).collect();
+    let _ = catch_unwind(AssertUnwindSafe(||{
+        drop(josievec.splice(1..2, (0..3).map(|i|{
+            if i == 2{
+                panic!("Boom! Panic!");
+            }
+            format!("new {i}")
+        })));
+    }));
+    println!("After panicking splice {:?}", josievec.as_slice());
+    assert_eq!(josievec.as_slice(), &["0", "new 0", "new 1", "2", "3", "4"]);
+}
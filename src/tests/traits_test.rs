@@ -0,0 +1,59 @@
+use std::{collections::{BTreeSet, HashMap}, io::Write};
+
+use crate::{josie_vec::{josievec_growth::OneAndAHalf, JosieVec}, josievec};
+
+///Uses josievecs as map keys, compares and orders them, and writes bytes into one through io::Write
+pub fn traits_test(){
+    println!("\nTraits test will compare, hash, order, index and format josievecs like slices\n\n");
+    let josievec = josievec![1, 2, 3];
+    println!("Debug prints the elements {josievec:?}");
+    assert_eq!(format!("{josievec:?}"), "[1, 2, 3]");
+
+    //equality ignores the growth policy and works against slices, arrays and vecs
+    let mut other = JosieVec::with_policy(OneAndAHalf);
+    other.extend([1, 2, 3]);
+    assert_eq!(josievec, other);
+    assert_eq!(josievec, [1, 2, 3]);
+    assert_eq!(josievec, &[1, 2, 3][..]);
+    assert_eq!(josievec, vec![1, 2, 3]);
+    assert_ne!(josievec, josievec![1, 2]);
+
+    //orders lexicographically like slices
+    let ordered:BTreeSet<JosieVec<i32>> = [josievec![2], josievec![1, 5], josievec![1], JosieVec::new()].into_iter().collect();
+    println!("Ordered josievecs {ordered:?}");
+    assert_eq!(ordered.into_iter().collect::<Vec<_>>(), [josievec![], josievec![1], josievec![1, 5], josievec![2]]);
+
+    //a josievec key can be looked up with a plain slice through Borrow
+    let mut map = HashMap::new();
+    map.insert(josievec!["a", "b"], 1);
+    map.insert(josievec!["c"], 2);
+    assert_eq!(map.get(&["a", "b"][..]), Some(&1));
+    assert_eq!(map.get(&josievec!["c"]), Some(&2));
+
+    //range indexing
+    let mut josievec = josievec![0, 1, 2, 3, 4];
+    assert_eq!(&josievec[1..3], &[1, 2]);
+    assert_eq!(&josievec[..=1], &[0, 1]);
+    josievec[3..].fill(9);
+    assert_eq!(josievec, [0, 1, 2, 9, 9]);
+    let slice:&[i32] = josievec.as_ref();
+    assert_eq!(slice.len(), 5);
+
+    //converting into an array only works for exactly matching lengths
+    let josievec = josievec![String::from("x"), String::from("y")];
+    let josievec = <[String; 3]>::try_from(josievec).unwrap_err();
+    let array:[String; 2] = josievec.try_into().unwrap();
+    assert_eq!(array, ["x", "y"]);
+
+    //extending from references copies
+    let mut josievec = josievec![1];
+    josievec.extend(&[2, 3]);
+    josievec.extend([4, 5].iter());
+    assert_eq!(josievec, [1, 2, 3, 4, 5]);
+
+    let mut bytes:JosieVec<u8> = JosieVec::new();
+    write!(bytes, "josie {}", 42).unwrap();
+    bytes.write_all(b"!").unwrap();
+    println!("Bytes written through io::Write {:?}", std::str::from_utf8(&bytes).unwrap());
+    assert_eq!(bytes, b"josie 42!");
+}
@@ -0,0 +1,24 @@
+use crate::josie_vec::{josievec_error::JosieTryReserveError, JosieVec};
+
+///Checks that oversized requests come back as errors instead of panicking, and that the vector is left usable afterwards
+pub fn try_reserve_test(){
+    println!("\nTry reserve test will request impossible capacities and check that errors are returned instead of panics\n\n");
+    let mut josievec:JosieVec<u64> = JosieVec::new();
+    //len + cap overflows usize
+    josievec.push(1);
+    assert_eq!(josievec.try_reserve(usize::MAX), Err(JosieTryReserveError::CapacityOverflow));
+    //cap * size_of::<u64>() overflows isize::MAX
+    assert_eq!(josievec.try_reserve_exact(usize::MAX / 4), Err(JosieTryReserveError::CapacityOverflow));
+    assert!(JosieVec::<u64>::try_with_capacity(usize::MAX / 2).is_err());
+    println!("Overflowing reserves returned errors, capacity is still {}", josievec.capacity());
+    //a failed reserve leaves the contents alone
+    assert_eq!(josievec.as_slice(), &[1]);
+    josievec.try_reserve(5).unwrap();
+    println!("Try reserve 5 with length 1 gave capacity {}", josievec.capacity());
+    for i in 2..=6{
+        josievec.try_push(i).unwrap_or_else(|(_, err)| panic!("{err}"));
+    }
+    println!("After try pushes contents are {:?}, capacity is {}", josievec.as_slice(), josievec.capacity());
+    let josievec:JosieVec<u8> = JosieVec::try_with_capacity(16).unwrap();
+    assert_eq!(josievec.capacity(), 16);
+}
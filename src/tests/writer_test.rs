@@ -0,0 +1,36 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::josie_vec::{josievec_extend::ExtendType, JosieVec};
+
+///Writes through the safe writer past its reservation, panics while it is live and fills spare capacity by hand
+pub fn writer_test(){
+    println!("\nWriter will push past the reserved capacity and check it regrows, then fills spare capacity by hand\n\n");
+    let mut josievec:JosieVec<String> = JosieVec::new();
+    {
+        let mut writer = josievec.writer(ExtendType::Exact(2));
+        writer.write_slice(&["a".to_string(), "b".to_string()]);
+        println!("Writer has {} slots remaining after filling the reservation", writer.remaining());
+        for i in 0..5{
+            writer.push(i.to_string());
+        }
+        assert_eq!(writer.written(), 7);
+    }
+    println!("After writer {:?}, capacity is {}", josievec.as_slice(), josievec.capacity());
+    assert_eq!(josievec.len(), 7);
+    //panicking while the writer is live keeps everything written before the panic
+    let _ = catch_unwind(AssertUnwindSafe(||{
+        let mut writer = josievec.writer(ExtendType::Ammortized);
+        writer.push("kept".to_string());
+        panic!("Boom! Panic!");
+    }));
+    assert_eq!(josievec.last().map(String::as_str), Some("kept"));
+
+    let mut numbers:JosieVec<u8> = JosieVec::with_capacity(4);
+    numbers.push(1);
+    let spare = numbers.spare_capacity_mut();
+    assert_eq!(spare.len(), 3);
+    spare[0].write(2);
+    spare[1].write(3);
+    unsafe{numbers.set_len(3)};
+    assert_eq!(numbers.as_slice(), &[1, 2, 3]);
+}
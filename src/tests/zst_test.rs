@@ -0,0 +1,55 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::josie_vec::JosieVec;
+
+//counts how many markers have been dropped so leaks and double drops show up
+static DROPPED:AtomicUsize = AtomicUsize::new(0);
+
+//zero sized marker type with a destructor
+#[derive(Debug, Clone)]
+struct Marker;
+
+impl Drop for Marker{
+    fn drop(&mut self){
+        DROPPED.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+fn dropped() -> usize{
+    DROPPED.load(Ordering::Relaxed)
+}
+
+///Runs zero sized types through every josievec code path and checks counts and drops line up
+pub fn zst_test(){
+    println!("\nZST test will push, extend, iterate and drain a JosieVec of zero sized markers\n\n");
+    let mut josievec:JosieVec<()> = JosieVec::with_capacity(10);
+    assert_eq!(josievec.capacity(), usize::MAX);
+    for _ in 0..1000{
+        josievec.push(());
+    }
+    josievec.extend([(); 24]);
+    josievec.extend((0..100).filter(|i| i % 2 == 0).map(|_| ()));
+    println!("JosieVec<()> length is {}, capacity is {}", josievec.len(), josievec.capacity());
+    assert_eq!(josievec.len(), 1074);
+    assert_eq!(josievec.into_iter().count(), 1074);
+
+    let start = dropped();
+    let mut markers = JosieVec::new();
+    markers.extend(std::iter::repeat_n(Marker, 10));
+    assert!(markers.pop().is_some());
+    markers.remove(0);
+    assert_eq!(dropped() - start, 2);
+    let clone = markers.clone();
+    markers.truncate(6);
+    assert_eq!(markers.len(), 6);
+    assert_eq!(dropped() - start, 4);
+    //consumes a few elements from into_iter then drops it with some left over
+    let mut iter = clone.into_iter();
+    iter.next();
+    iter.next();
+    drop(iter);
+    assert_eq!(dropped() - start, 12);
+    markers.clear();
+    assert_eq!(dropped() - start, 18);
+    println!("All {} markers were dropped exactly once", dropped() - start);
+}